@@ -71,13 +71,9 @@ fn email(text: &str) -> Option<Email> {
         suffix: "".to_string(),
     };
 
-    let regex = match Regex::new(r"^(?<prefix>[\w\-\.]+)@(?<suffix>[\w-]+\.+[\w-]{2,4})$")
+    let regex = Regex::new(r"^(?<prefix>[\w\-\.]+)@(?<suffix>[\w-]+\.+[\w-]{2,4})$")
         .expect("Regex should be fine")
-        .captures(text)
-    {
-        Some(s) => s,
-        None => return None,
-    };
+        .captures(text)?;
 
     if let Some(item) = regex.name("prefix") {
         email.prefix = item.as_str().to_string();
@@ -98,10 +94,7 @@ fn main() -> io::Result<()> {
     handle_panics();
 
     println!("Input your age!");
-    let num = match input(|e| match e.parse::<i32>() {
-        Ok(t) => Some(t),
-        Err(_) => None,
-    }) {
+    let num = match input(|e| e.parse::<i32>().ok()) {
         Ok(t) => t,
         Err(_) => return Ok(()),
     };
@@ -66,13 +66,7 @@ impl<E: Render> Player<E> {
     }
 
     pub fn update(&mut self, window: &mut Window) {
-        self.input = 0;
-        if event!(window, Event::Key(e) => e.code == KeyCode::Right) {
-            self.input = 1;
-        }
-        if event!(window, Event::Key(e) => e.code == KeyCode::Right) {
-            self.input = -1;
-        }
+        self.input = window.axis(KeyCode::Left, KeyCode::Right) as i32;
 
         self.loc.x = (self.loc.x as i32 + self.input).clamp(0, window.size().x as i32) as u16;
     }
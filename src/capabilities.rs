@@ -0,0 +1,125 @@
+use crossterm::terminal;
+
+/// The optional features the terminal supports, probed once and cached.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let supports = Supports::detect();
+
+if supports.truecolor() {
+    // Use rgb colors
+}
+```
+*/
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Supports {
+    keyboard: bool,
+    truecolor: bool,
+    synchronized_output: bool,
+}
+
+/// Terminals known to handle synchronized output, by their `TERM_PROGRAM`.
+const SYNCHRONIZED_TERMINALS: [&str; 6] = [
+    "WezTerm",
+    "iTerm.app",
+    "ghostty",
+    "contour",
+    "vscode",
+    "rio",
+];
+
+impl Supports {
+    /// Creates a set of capabilities from known values, such as for tests or a user override.
+    pub fn new(keyboard: bool, truecolor: bool, synchronized_output: bool) -> Self {
+        Self {
+            keyboard,
+            truecolor,
+            synchronized_output,
+        }
+    }
+
+    /// Probes the terminal for its capabilities.
+    /// The keyboard protocol is queried from the terminal, so this should run after raw mode is enabled,
+    /// while the others are guessed from the environment.
+    pub fn detect() -> Self {
+        Self::from_env(
+            terminal::supports_keyboard_enhancement().unwrap_or(false),
+            |name| std::env::var(name).ok(),
+        )
+    }
+
+    /// Works out the capabilities from the result of the keyboard query and a lookup of environment variables.
+    pub fn from_env(keyboard: bool, var: impl Fn(&str) -> Option<String>) -> Self {
+        let truecolor = var("COLORTERM").is_some_and(|c| c == "truecolor" || c == "24bit");
+
+        let synchronized_output = var("TERM_PROGRAM")
+            .is_some_and(|term| SYNCHRONIZED_TERMINALS.contains(&term.as_str()))
+            || var("TERM").is_some_and(|term| {
+                ["kitty", "foot", "alacritty"]
+                    .iter()
+                    .any(|name| term.contains(name))
+            });
+
+        Self::new(keyboard, truecolor, synchronized_output)
+    }
+
+    /// Returns true if the terminal supports the kitty keyboard protocol.
+    pub fn keyboard(&self) -> bool {
+        self.keyboard
+    }
+
+    /// Returns true if the terminal can display 24 bit rgb colors.
+    pub fn truecolor(&self) -> bool {
+        self.truecolor
+    }
+
+    /// Returns true if the terminal supports synchronized output, letting whole frames be drawn at once.
+    pub fn synchronized_output(&self) -> bool {
+        self.synchronized_output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_vars(vars: &[(&str, &str)]) -> Supports {
+        Supports::from_env(false, |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn colorterm_enables_truecolor() {
+        assert!(from_vars(&[("COLORTERM", "truecolor")]).truecolor());
+        assert!(from_vars(&[("COLORTERM", "24bit")]).truecolor());
+        assert!(!from_vars(&[("COLORTERM", "yes")]).truecolor());
+    }
+
+    #[test]
+    fn term_alone_enables_nothing_extra() {
+        assert_eq!(
+            from_vars(&[("TERM", "xterm-256color")]),
+            Supports::new(false, false, false)
+        );
+        assert_eq!(from_vars(&[("TERM", "dumb")]), Supports::default());
+        assert_eq!(from_vars(&[]), Supports::default());
+    }
+
+    #[test]
+    fn known_terminals_enable_synchronized_output() {
+        assert!(from_vars(&[("TERM", "xterm-kitty")]).synchronized_output());
+        assert!(from_vars(&[("TERM_PROGRAM", "WezTerm")]).synchronized_output());
+        assert!(!from_vars(&[("TERM_PROGRAM", "Apple_Terminal")]).synchronized_output());
+    }
+
+    #[test]
+    fn keyboard_comes_from_the_query() {
+        assert!(Supports::from_env(true, |_| None).keyboard());
+        assert!(!Supports::from_env(false, |_| None).keyboard());
+    }
+}
@@ -0,0 +1,81 @@
+use std::{fmt, io};
+
+use crossterm::{execute, Command};
+
+use crate::window::Window;
+
+/// A command that sets the system clipboard using the OSC 52 escape sequence.
+///
+/// OSC 52 is handled entirely by the terminal, so it works over ssh and needs no platform libraries,
+/// but not every terminal supports it. Some (such as tmux or screen) need it enabled explicitly,
+/// and others silently ignore it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetClipboard<T: AsRef<str>>(pub T);
+
+impl<T: AsRef<str>> Command for SetClipboard<T> {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "\x1b]52;c;{}\x07", base64(self.0.as_ref().as_bytes()))
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "OSC 52 is not supported through the winapi",
+        ))
+    }
+}
+
+impl Window {
+    /// Sets the system clipboard to the given text through the terminal.
+    /// See `SetClipboard` for caveats about terminal support.
+    pub fn set_clipboard(&mut self, text: &str) -> io::Result<()> {
+        execute!(self.io(), SetClipboard(text))
+    }
+}
+
+/// Encodes the bytes as standard, padded base64.
+fn base64(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut res = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - i * 8));
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                res.push(TABLE[(n >> (18 - i * 6)) as usize & 0x3f] as char);
+            } else {
+                res.push('=');
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_pads_each_remainder() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foob"), "Zm9vYg==");
+        assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xff, 0xfe, 0x00]), "//4A");
+    }
+
+    #[test]
+    fn set_clipboard_sequence() {
+        let mut ansi = String::new();
+        SetClipboard("hi").write_ansi(&mut ansi).unwrap();
+        assert_eq!(ansi, "\x1b]52;c;aGk=\x07");
+    }
+}
@@ -0,0 +1,84 @@
+/// Moves at a constant rate.
+pub fn linear(t: f32) -> f32 {
+    t.clamp(0.0, 1.0)
+}
+
+/// Starts slow and speeds up.
+pub fn ease_in_quad(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t
+}
+
+/// Starts fast and slows down.
+pub fn ease_out_quad(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    1.0 - (1.0 - t) * (1.0 - t)
+}
+
+/// Starts slow, speeds up through the middle, and slows down again.
+pub fn ease_in_out(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+    }
+}
+
+/// Starts slow and speeds up, more sharply than `ease_in_quad`.
+pub fn ease_in_cubic(t: f32) -> f32 {
+    t.clamp(0.0, 1.0).powi(3)
+}
+
+/// Starts fast and slows down, more sharply than `ease_out_quad`.
+pub fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t.clamp(0.0, 1.0)).powi(3)
+}
+
+/// Like `ease_in_out`, with a sharper speed up and slow down.
+pub fn ease_in_out_cubic(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    if t < 0.5 {
+        4.0 * t.powi(3)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// Returns the value between start and end at the given progress, following the easing curve.
+/// Every curve here runs from 0.0 to 1.0, with progress outside of that range clamped.
+/**
+`Example`
+```rust
+use ascii_forge::ease::{ease_out_cubic, tween};
+
+let x = tween(0.0, 40.0, 0.5, ease_out_cubic);
+assert!(x > 20.0 && x < 40.0);
+```
+*/
+pub fn tween(start: f32, end: f32, t: f32, easing: impl Fn(f32) -> f32) -> f32 {
+    start + (end - start) * easing(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [fn(f32) -> f32; 4] = [linear, ease_in_out, ease_out_cubic, ease_in_out_cubic];
+
+    #[test]
+    fn tween_hits_both_endpoints() {
+        for easing in CURVES {
+            assert_eq!(tween(10.0, 30.0, 0.0, easing), 10.0);
+            assert_eq!(tween(10.0, 30.0, 1.0, easing), 30.0);
+        }
+    }
+
+    #[test]
+    fn curves_never_move_backwards() {
+        for easing in CURVES {
+            let samples: Vec<f32> = (0..=100).map(|i| easing(i as f32 / 100.0)).collect();
+            assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+    }
+}
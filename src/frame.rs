@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+/// A single frame being drawn, borrowing the window's active buffer
+/// along with read-only access to the input for the frame.
+/// Handed out by `Window::draw`, keeping rendering code apart from the window's lifecycle.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+window.draw(|frame| {
+    render!(frame, vec2(0, 0) => [ "Hello World!" ]);
+
+    if event!(frame, Event::Key(e) => e.code == KeyCode::Enter) {
+        render!(frame, vec2(0, 1) => [ "Enter Pressed!" ]);
+    }
+});
+# Ok(())
+# }
+```
+*/
+pub struct Frame<'a> {
+    buffer: &'a mut Buffer,
+    events: &'a [Event],
+    mouse_pos: Vec2,
+}
+
+impl AsMut<Buffer> for Frame<'_> {
+    fn as_mut(&mut self) -> &mut Buffer {
+        self.buffer
+    }
+}
+
+impl<'a> Frame<'a> {
+    pub fn new(buffer: &'a mut Buffer, events: &'a [Event], mouse_pos: Vec2) -> Self {
+        Self {
+            buffer,
+            events,
+            mouse_pos,
+        }
+    }
+
+    /// Returns the buffer being drawn to, as a reference.
+    pub fn buffer(&self) -> &Buffer {
+        self.buffer
+    }
+
+    /// Returns the buffer being drawn to, as a mutable reference.
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        self.buffer
+    }
+
+    /// Returns the size of the frame.
+    pub fn size(&self) -> Vec2 {
+        self.buffer.size()
+    }
+
+    pub fn mouse_pos(&self) -> Vec2 {
+        self.mouse_pos
+    }
+
+    /// Returns the events for the frame.
+    pub fn events(&self) -> &[Event] {
+        self.events
+    }
+}
@@ -2,8 +2,29 @@ pub mod renderer;
 
 pub mod window;
 
+pub mod frame;
+
+pub mod recorder;
+
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+
 pub mod math;
 
+pub mod capabilities;
+
+pub mod style;
+
+pub mod text;
+
+pub mod time;
+
+pub mod transition;
+
+pub mod ease;
+
+pub mod widgets;
+
 pub mod prelude;
 
 // Export required crates
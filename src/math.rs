@@ -1,7 +1,7 @@
 /// A 2d Vector that has no math, is only used as a pretty version of a tuple of u16s
 /// Can be made from (u16, u16).
 /// Using a single u16.into() will create a vec2 where both values are the same.
-#[derive(Default, Debug, Eq, PartialEq, PartialOrd, Ord, Copy, Clone)]
+#[derive(Default, Debug, Eq, PartialEq, PartialOrd, Ord, Copy, Clone, Hash)]
 pub struct Vec2 {
     pub x: u16,
     pub y: u16,
@@ -23,3 +23,79 @@ impl From<u16> for Vec2 {
 pub fn vec2(x: u16, y: u16) -> Vec2 {
     Vec2 { x, y }
 }
+
+/// A rectangle made from a location and a size, used to describe a region of a buffer.
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Rect {
+    pub loc: Vec2,
+    pub size: Vec2,
+}
+
+impl Rect {
+    /// Creates a Rect at the given location with the given size.
+    pub fn new(loc: impl Into<Vec2>, size: impl Into<Vec2>) -> Self {
+        Self {
+            loc: loc.into(),
+            size: size.into(),
+        }
+    }
+
+    /// Returns true if the given point lies within the rect.
+    pub fn contains(&self, point: impl Into<Vec2>) -> bool {
+        let point = point.into();
+        point.x >= self.loc.x
+            && point.x - self.loc.x < self.size.x
+            && point.y >= self.loc.y
+            && point.y - self.loc.y < self.size.y
+    }
+
+    /// Returns true if the rects share at least one cell.
+    /// Rects that only touch along an edge, or that have no size, don't collide.
+    pub fn collides(&self, other: &Rect) -> bool {
+        rects_overlap(*self, *other)
+    }
+}
+
+/// Returns true if the rects share at least one cell.
+/// Rects that only touch along an edge, or that have no size, don't overlap.
+pub fn rects_overlap(a: Rect, b: Rect) -> bool {
+    let overlaps = |a_start: u16, a_len: u16, b_start: u16, b_len: u16| {
+        a_len > 0
+            && b_len > 0
+            && (a_start as u32) < b_start as u32 + b_len as u32
+            && (b_start as u32) < a_start as u32 + a_len as u32
+    };
+
+    overlaps(a.loc.x, a.size.x, b.loc.x, b.size.x) && overlaps(a.loc.y, a.size.y, b.loc.y, b.size.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn touching_rects_do_not_collide() {
+        let a = Rect::new((0, 0), (2, 2));
+
+        assert!(!a.collides(&Rect::new((2, 0), (2, 2))));
+        assert!(!a.collides(&Rect::new((0, 2), (2, 2))));
+    }
+
+    #[test]
+    fn overlapping_rects_collide() {
+        let a = Rect::new((0, 0), (3, 3));
+        let b = Rect::new((2, 2), (3, 3));
+
+        assert!(rects_overlap(a, b));
+        assert!(b.collides(&a));
+        assert!(a.collides(&Rect::new((1, 1), (1, 1))));
+    }
+
+    #[test]
+    fn disjoint_and_empty_rects_do_not_collide() {
+        let a = Rect::new((0, 0), (2, 2));
+
+        assert!(!rects_overlap(a, Rect::new((5, 5), (2, 2))));
+        assert!(!rects_overlap(a, Rect::new((1, 1), (0, 0))));
+    }
+}
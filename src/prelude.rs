@@ -1,8 +1,21 @@
 #![allow(unused_imports)]
+pub use crate::capabilities::*;
+#[cfg(feature = "clipboard")]
+pub use crate::clipboard::*;
 pub use crate::event;
+pub use crate::frame::*;
 pub use crate::math::*;
+pub use crate::recorder::*;
 pub use crate::render;
+pub use crate::render_list;
 pub use crate::renderer::{buffer::*, cell::*, render::*};
+pub use crate::style::{color::*, theme::*};
+pub use crate::text::*;
+pub use crate::time::*;
+pub use crate::widgets::{
+    checkbox::*, debug::*, grid::*, hoverable::*, line::*, marquee::*, offset::*, radio::*,
+    rule::*, sprite::*, tabs::*, text::*, tree::*,
+};
 pub use crate::window::*;
 
 pub use crossterm;
@@ -0,0 +1,142 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::math::Vec2;
+
+/// Records everything written to the terminal as an asciinema v2 `.cast` file.
+/// Output is collected as it is written, and saved as a single timestamped event on each flush.
+/// Usually created through `Window::record_to`.
+pub struct Recorder {
+    file: BufWriter<File>,
+    start: Instant,
+    pending: Vec<u8>,
+}
+
+impl Recorder {
+    /// Creates the cast file at the given path, writing the header for a terminal of the given size.
+    pub fn new(path: impl AsRef<Path>, size: Vec2) -> io::Result<Self> {
+        let mut file = BufWriter::new(File::create(path)?);
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|t| t.as_secs())
+            .unwrap_or_default();
+
+        writeln!(
+            file,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}, \"timestamp\": {}}}",
+            size.x, size.y, timestamp
+        )?;
+
+        Ok(Self {
+            file,
+            start: Instant::now(),
+            pending: vec![],
+        })
+    }
+
+    /// Stores output that will be saved with the next flush.
+    pub fn record(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+    }
+
+    /// Saves any pending output as an event, timestamped from the start of the recording.
+    pub fn flush(&mut self) -> io::Result<()> {
+        // Hold back a trailing partial character until the rest of it is written.
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(_) => self.pending.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.pending.len(),
+        };
+
+        if valid == 0 {
+            return Ok(());
+        }
+
+        let rest = self.pending.split_off(valid);
+        let text = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending = rest;
+
+        writeln!(
+            self.file,
+            "[{:.6}, \"o\", \"{}\"]",
+            self.start.elapsed().as_secs_f64(),
+            escape(&text)
+        )?;
+        self.file.flush()
+    }
+}
+
+/// Escapes text for use inside of a JSON string.
+fn escape(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for chr in text.chars() {
+        match chr {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                res.push_str(&format!("\\u{:04x}", c as u32))
+            }
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::*;
+    use crate::math::vec2;
+
+    fn cast_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("ascii-forge-{name}-{}.cast", std::process::id()))
+    }
+
+    #[test]
+    fn header_is_asciinema_v2() {
+        let path = cast_path("header");
+        drop(Recorder::new(&path, vec2(80, 24)).unwrap());
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let header =
+            Regex::new(r#"^\{"version": 2, "width": 80, "height": 24, "timestamp": \d+\}\n$"#);
+        assert!(header.unwrap().is_match(&cast), "{cast:?}");
+    }
+
+    #[test]
+    fn output_is_saved_as_escaped_events() {
+        let path = cast_path("events");
+        let mut recorder = Recorder::new(&path, vec2(80, 24)).unwrap();
+
+        recorder.record(b"\x1b[1;1H\"hi\"\\\r\n\t\x7f");
+        // Only the first byte of an 'é', held back until the rest arrives.
+        recorder.record(&[0xc3]);
+        recorder.flush().unwrap();
+        recorder.record(&[0xa9]);
+        recorder.flush().unwrap();
+        // Nothing pending, so nothing is saved.
+        recorder.flush().unwrap();
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let event = Regex::new(r#"^\[\d+\.\d{6}, "o", "(.*)"\]$"#).unwrap();
+        let events: Vec<_> = cast
+            .lines()
+            .skip(1)
+            .map(|line| event.captures(line).expect(line)[1].to_string())
+            .collect();
+        assert_eq!(events, [r#"\u001b[1;1H\"hi\"\\\r\n\t\u007f"#, "é"]);
+    }
+}
@@ -1,3 +1,8 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
 use crate::prelude::*;
 
 /**
@@ -10,18 +15,17 @@ This is the backbone of ascii-forge
 use ascii_forge::prelude::*;
 
 // A 30x30 buffer window
-let mut buffer = Buffer::new(30, 30);
+let mut buffer = Buffer::new((30, 30));
 
 // Render Hello World to the top left of the buffer
 render!(
-    buffer, {
-        (0, 0) => "Hello World!"
-    }
+    buffer,
+    vec2(0, 0) => [ "Hello World!" ]
 );
 ```
 
 */
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Buffer {
     size: Vec2,
     cells: Vec<Cell>,
@@ -48,16 +52,126 @@ impl Buffer {
         self.size
     }
 
+    /// Returns the width of the buffer, the same as `size().x`.
+    pub fn width(&self) -> u16 {
+        self.size.x
+    }
+
+    /// Returns the height of the buffer, the same as `size().y`.
+    pub fn height(&self) -> u16 {
+        self.size.y
+    }
+
+    /// Returns every cell of the buffer in row-major order,
+    /// so the cell at `(x, y)` is at index `y * width + x`.
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Returns every cell of the buffer mutably in row-major order,
+    /// so the cell at `(x, y)` is at index `y * width + x`.
+    /// Useful for effects that touch every cell, without the cost of looking each one up.
+    pub fn cells_mut(&mut self) -> &mut [Cell] {
+        &mut self.cells
+    }
+
     /// Sets a cell at the given location to the given cell
+    /// Locations outside of the buffer are ignored.
     pub fn set<C: Into<Cell>>(&mut self, loc: impl Into<Vec2>, cell: C) {
-        let idx = self.index_of(loc);
+        let loc = loc.into();
+        if loc.x >= self.size.x || loc.y >= self.size.y {
+            return;
+        }
 
+        let idx = self.index_of(loc);
         self.cells[idx] = cell.into();
     }
 
+    /// Writes an unstyled string starting at the given location, one character per cell,
+    /// starting newlines back at the same column. Anything past the edges of the buffer is clipped.
+    /// Wide characters (such as CJK and most emoji) take up two cells, leaving the second one empty,
+    /// and combining marks are joined onto the character before them.
+    /// Returns the location just past the last character.
+    pub fn set_str(&mut self, loc: impl Into<Vec2>, text: &str) -> Vec2 {
+        let mut loc = loc.into();
+        let base_x = loc.x;
+        for line in text.split('\n') {
+            loc.x = base_x;
+            if loc.y < self.size.y {
+                let mut last: Option<usize> = None;
+                for chr in line.chars() {
+                    let width = char_width(chr);
+                    if width == 0 {
+                        if let Some(idx) = last {
+                            let cell = &self.cells[idx];
+                            self.cells[idx] =
+                                Cell::new(format!("{}{chr}", cell.text()), *cell.style());
+                        }
+                        continue;
+                    }
+
+                    if loc.x.saturating_add(width) > self.size.x {
+                        break;
+                    }
+                    let idx = self.index_of(loc);
+                    self.cells[idx] = Cell::chr(chr);
+                    last = Some(idx);
+
+                    // The terminal draws the character over the next cell as well,
+                    // so it is left empty rather than drawn over the character's right half.
+                    if width == 2 {
+                        let idx = self.index_of(vec2(loc.x + 1, loc.y));
+                        self.cells[idx] = Cell::string("");
+                    }
+                    loc.x += width;
+                }
+            }
+            loc.y = loc.y.saturating_add(1);
+        }
+        loc.y = loc.y.saturating_sub(1);
+        loc
+    }
+
+    /// Word wraps the text to the width of the rect and writes it in the given style, starting newlines on a new row.
+    /// Rows past the bottom of the rect are clipped.
+    /// Returns the number of rows written, so that following content can be placed below it.
+    pub fn draw_text_wrapped(&mut self, rect: Rect, text: &str, style: ContentStyle) -> u16 {
+        let lines = Text::new(
+            text.split('\n')
+                .map(|line| Line::from(StyledContent::new(style, line))),
+        )
+        .with_wrap(rect.size.x)
+        .wrapped();
+
+        let end_x = rect.loc.x.saturating_add(rect.size.x).min(self.size.x);
+        let mut rows = 0;
+        for (line, y) in lines
+            .iter()
+            .zip(rect.loc.y..rect.loc.y.saturating_add(rect.size.y))
+        {
+            if y >= self.size.y {
+                break;
+            }
+            let chars = line.spans.iter().flat_map(|span| span.content().chars());
+            for (chr, x) in chars.zip(rect.loc.x..end_x) {
+                self.set((x, y), StyledContent::new(style, chr));
+            }
+            rows += 1;
+        }
+        rows
+    }
+
     /// Sets all cells at the given location to the given cell
+    /// With the `parallel` feature, large buffers are filled across several threads.
     pub fn fill<C: Into<Cell>>(&mut self, cell: C) {
         let cell = cell.into();
+
+        #[cfg(feature = "parallel")]
+        if self.cells.len() >= PARALLEL_FILL_THRESHOLD {
+            parallel_fill(&mut self.cells, &cell);
+            return;
+        }
+
         for i in 0..self.cells.len() {
             self.cells[i] = cell.clone()
         }
@@ -75,13 +189,207 @@ impl Buffer {
         &mut self.cells[idx]
     }
 
+    /// Returns an iterator over every cell and its location, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &Cell)> {
+        let width = self.size.x.max(1) as usize;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (vec2((i % width) as u16, (i / width) as u16), cell))
+    }
+
+    /// Returns a mutable iterator over every cell and its location, in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vec2, &mut Cell)> {
+        let width = self.size.x.max(1) as usize;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (vec2((i % width) as u16, (i / width) as u16), cell))
+    }
+
+    /// Returns an iterator over the cells of the given row, from left to right.
+    pub fn row(&self, y: u16) -> impl Iterator<Item = &Cell> {
+        (0..self.size.x).map(move |x| self.get((x, y)))
+    }
+
+    /// Returns an iterator over the cells of the given column, from top to bottom.
+    pub fn column(&self, x: u16) -> impl Iterator<Item = &Cell> {
+        (0..self.size.y).map(move |y| self.get((x, y)))
+    }
+
+    /// Returns true if every cell within the rect is empty.
+    /// The rect is clipped to the buffer, so any part of it outside of the buffer counts as empty.
+    pub fn is_region_empty(&self, rect: Rect) -> bool {
+        let end_x = rect.loc.x.saturating_add(rect.size.x).min(self.size.x);
+        let end_y = rect.loc.y.saturating_add(rect.size.y).min(self.size.y);
+
+        (rect.loc.y..end_y).all(|y| (rect.loc.x..end_x).all(|x| self.get((x, y)).is_empty()))
+    }
+
+    /// Runs the given closure on the style of every cell within the rect, clipped to the buffer.
+    /// Useful for recoloring or greying out a whole region in one call.
+    pub fn map_style(&mut self, rect: Rect, f: impl Fn(&mut ContentStyle)) {
+        let end_x = rect.loc.x.saturating_add(rect.size.x).min(self.size.x);
+        let end_y = rect.loc.y.saturating_add(rect.size.y).min(self.size.y);
+
+        for y in rect.loc.y..end_y {
+            for x in rect.loc.x..end_x {
+                f(self.get_mut((x, y)).style_mut());
+            }
+        }
+    }
+
+    /// Darkens every color in the buffer towards black by the given amount, from 0 to 1.
+    /// Cells using the terminal's default colors are left alone, as those colors can't be known.
+    pub fn dim(&mut self, amount: f32) {
+        for cell in self.cells.iter_mut() {
+            let style = cell.style_mut();
+            for color in [&mut style.foreground_color, &mut style.background_color] {
+                if let Some(dimmed) = color.and_then(|c| mix(c, Color::Black, amount)) {
+                    *color = Some(dimmed);
+                }
+            }
+        }
+    }
+
+    /// Swaps the foreground and background colors of every cell using the map, leaving unmapped colors alone.
+    /// Useful for recoloring a single piece of art for different themes or teams.
+    pub fn remap_colors(&mut self, map: &HashMap<Color, Color>) {
+        for cell in self.cells.iter_mut() {
+            let style = cell.style_mut();
+            for color in [&mut style.foreground_color, &mut style.background_color] {
+                if let Some(new) = color.and_then(|c| map.get(&c)) {
+                    *color = Some(*new);
+                }
+            }
+        }
+    }
+
+    /// Returns a hash of the buffer's size and every cell's text and style.
+    /// Comparing hashes between frames is a cheap way to tell whether anything changed,
+    /// though the value may differ between builds of the program, so it shouldn't be stored.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.size.hash(&mut hasher);
+        for cell in &self.cells {
+            cell.text().hash(&mut hasher);
+
+            // ContentStyle doesn't implement Hash, so its parts are hashed one at a time.
+            let style = cell.style();
+            style.foreground_color.hash(&mut hasher);
+            style.background_color.hash(&mut hasher);
+            style.underline_color.hash(&mut hasher);
+            for attribute in Attribute::iterator() {
+                style.attributes.has(attribute).hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Returns the text of the cell at the given location, ignoring its style.
+    /// None if the location is outside of the buffer.
+    pub fn text_at(&self, loc: impl Into<Vec2>) -> Option<&str> {
+        let loc = loc.into();
+        if loc.x >= self.size.x || loc.y >= self.size.y {
+            return None;
+        }
+        Some(self.get(loc).text())
+    }
+
+    /// Returns the text of each row without any styling, with trailing blanks trimmed.
+    pub fn to_lines(&self) -> Vec<String> {
+        (0..self.size.y)
+            .map(|y| {
+                let line: String = self.row(y).map(|c| c.text()).collect();
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    /// Returns the text of the buffer without any styling, one line per row with trailing blanks trimmed.
+    pub fn to_plain_string(&self) -> String {
+        self.to_lines().join("\n")
+    }
+
+    /// Joins the light box-drawing lines within the rect where they meet,
+    /// so that touching borders become proper junctions like `├`, `┬`, or `┼`.
+    /// A line gains an arm towards any neighbor that has an arm pointing back at it.
+    /// A line crossing side by side borders, like `─││─`, is carried through all of them as `─┼┼─`.
+    pub fn connect_box_drawing(&mut self, rect: Rect) {
+        let start = vec2(rect.loc.x.min(self.size.x), rect.loc.y.min(self.size.y));
+        let end_x = rect.loc.x.saturating_add(rect.size.x).min(self.size.x);
+        let end_y = rect.loc.y.saturating_add(rect.size.y).min(self.size.y);
+        let width = (end_x - start.x) as usize;
+        let height = (end_y - start.y) as usize;
+
+        let arms_at = |buffer: &Buffer, x: Option<u16>, y: Option<u16>| match (x, y) {
+            (Some(x), Some(y)) if x < buffer.size.x && y < buffer.size.y => {
+                box_arms(buffer.get((x, y)).text()).unwrap_or(0)
+            }
+            _ => 0,
+        };
+
+        // The glyph's own arms, and the arms it ends up with, for each cell of the rect.
+        let mut cells = vec![None; width * height];
+        for y in start.y..end_y {
+            for x in start.x..end_x {
+                let Some(own) = box_arms(self.get((x, y)).text()) else {
+                    continue;
+                };
+
+                let mut arms = own;
+                if arms_at(self, Some(x), y.checked_sub(1)) & DOWN != 0 {
+                    arms |= UP;
+                }
+                if arms_at(self, Some(x), y.checked_add(1)) & UP != 0 {
+                    arms |= DOWN;
+                }
+                if arms_at(self, x.checked_sub(1), Some(y)) & RIGHT != 0 {
+                    arms |= LEFT;
+                }
+                if arms_at(self, x.checked_add(1), Some(y)) & LEFT != 0 {
+                    arms |= RIGHT;
+                }
+
+                let idx = (y - start.y) as usize * width + (x - start.x) as usize;
+                cells[idx] = Some((own, arms));
+            }
+        }
+
+        // Side by side lines only point along themselves, so a line entering one side of them
+        // has to be carried through to the other by hand. Rows of `│`, then columns of `─`.
+        for row in 0..height {
+            let run: Vec<usize> = (0..width).map(|x| row * width + x).collect();
+            bridge_runs(&mut cells, &run, UP | DOWN, LEFT, RIGHT);
+        }
+        for column in 0..width {
+            let run: Vec<usize> = (0..height).map(|y| y * width + column).collect();
+            bridge_runs(&mut cells, &run, LEFT | RIGHT, UP, DOWN);
+        }
+
+        for (idx, cell) in cells.into_iter().enumerate() {
+            let Some((own, arms)) = cell else {
+                continue;
+            };
+            if arms != own {
+                let loc = vec2(
+                    start.x + (idx % width) as u16,
+                    start.y + (idx / width) as u16,
+                );
+                let style = *self.get(loc).style();
+                self.set(loc, Cell::new(box_glyph(arms), style));
+            }
+        }
+    }
+
     fn index_of(&self, loc: impl Into<Vec2>) -> usize {
         let loc = loc.into();
         let idx = loc.y as usize * self.size.x as usize + loc.x as usize;
+        let len = self.size.x as usize * self.size.y as usize;
 
-        debug_assert!((idx as u16) < self.size.x * self.size.y);
+        debug_assert!(idx < len, "{loc:?} is out of bounds for {:?}", self.size);
 
-        idx.min((self.size.x as usize * self.size.y as usize) - 1)
+        idx.min(len - 1)
     }
 
     /// Clears the buffer
@@ -90,11 +398,21 @@ impl Buffer {
     }
 
     /// Returns the cells and locations that are different between the two buffers
+    ///
+    /// If the buffers are not the same size (for example mid-resize), there is nothing to
+    /// compare against, so every cell of `other` is returned, acting as a full redraw.
     pub fn diff<'a>(&self, other: &'a Buffer) -> Vec<(Vec2, &'a Cell)> {
-        assert!(self.size == other.size);
-
         let mut res = vec![];
 
+        if self.size != other.size {
+            for x in 0..other.size.x {
+                for y in 0..other.size.y {
+                    res.push((vec2(x, y), other.get((x, y))))
+                }
+            }
+            return res;
+        }
+
         for x in 0..self.size.x {
             for y in 0..self.size.y {
                 if self.get((x, y)) != other.get((x, y)) {
@@ -106,6 +424,54 @@ impl Buffer {
         res
     }
 
+    /// Returns a readable description of every difference between the two buffers,
+    /// one line per mismatched cell, or an empty string if they match.
+    /// Size mismatches are reported first, followed by the cells the buffers share.
+    pub fn visual_diff(&self, other: &Buffer) -> String {
+        let mut lines = vec![];
+
+        if self.size != other.size {
+            lines.push(format!(
+                "size mismatch: left is {}x{}, right is {}x{}",
+                self.size.x, self.size.y, other.size.x, other.size.y
+            ));
+        }
+
+        for y in 0..self.size.y.min(other.size.y) {
+            for x in 0..self.size.x.min(other.size.x) {
+                let (left, right) = (self.get((x, y)), other.get((x, y)));
+                if left.text() != right.text() {
+                    lines.push(format!(
+                        "({x}, {y}): left {:?}, right {:?}",
+                        left.text(),
+                        right.text()
+                    ));
+                } else if left.style() != right.style() {
+                    lines.push(format!(
+                        "({x}, {y}): {:?} styled differently, left {:?}, right {:?}",
+                        left.text(),
+                        left.style(),
+                        right.style()
+                    ));
+                }
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Panics with the `visual_diff` between the buffers if they don't match.
+    /// Meant for tests, where it shows exactly which cells differ.
+    #[track_caller]
+    pub fn assert_matches(&self, expected: &Buffer) {
+        if self != expected {
+            panic!(
+                "buffers do not match (left is actual, right is expected)\n{}",
+                self.visual_diff(expected)
+            );
+        }
+    }
+
     /// Shrinks the buffer to the given size by dropping any cells that are only whitespace
     pub fn shrink(&mut self) {
         let mut max_whitespace_x = 0;
@@ -122,6 +488,62 @@ impl Buffer {
         self.resize(vec2(max_whitespace_x + 1, max_whitespace_y + 1));
     }
 
+    /// Crops away any blank rows and columns around the edges of the buffer,
+    /// returning the trimmed buffer and the location it was cropped from.
+    /// Unlike `shrink`, leading blank space is removed as well.
+    pub fn trim(&self) -> (Buffer, Vec2) {
+        let mut min = self.size;
+        let mut max = vec2(0, 0);
+        for y in 0..self.size.y {
+            for x in 0..self.size.x {
+                if !self.get((x, y)).is_empty() {
+                    min = vec2(min.x.min(x), min.y.min(y));
+                    max = vec2(max.x.max(x), max.y.max(y));
+                }
+            }
+        }
+
+        if min.x > max.x || min.y > max.y {
+            return (Buffer::new((0, 0)), vec2(0, 0));
+        }
+
+        let mut buffer = Buffer::new((max.x - min.x + 1, max.y - min.y + 1));
+        for y in 0..buffer.size.y {
+            for x in 0..buffer.size.x {
+                buffer.set((x, y), self.get((min.x + x, min.y + y)).clone());
+            }
+        }
+
+        (buffer, min)
+    }
+
+    /// Returns a copy of the buffer rotated clockwise by the given number of quarter turns.
+    /// Odd turns swap the width and height.
+    /// Each cell is moved as a single unit, so text wider than one column won't line up once turned on its side.
+    pub fn rotate(&self, quarter_turns: u8) -> Buffer {
+        let (w, h) = (self.size.x, self.size.y);
+        let turns = quarter_turns % 4;
+        let size = if turns % 2 == 1 {
+            vec2(h, w)
+        } else {
+            self.size
+        };
+
+        let mut buffer = Buffer::new(size);
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let from = match turns {
+                    0 => vec2(x, y),
+                    1 => vec2(y, h - 1 - x),
+                    2 => vec2(w - 1 - x, h - 1 - y),
+                    _ => vec2(w - 1 - y, x),
+                };
+                buffer.set((x, y), self.get(from).clone());
+            }
+        }
+        buffer
+    }
+
     /// Resizes the buffer while retaining elements that have already been rendered
     pub fn resize(&mut self, new_size: impl Into<Vec2>) {
         let new_size = new_size.into();
@@ -133,7 +555,11 @@ impl Buffer {
 
         for y in 0..new_size.y {
             for x in 0..new_size.x {
-                new_elements.push(self.get((x, y)).clone());
+                if x < self.size.x && y < self.size.y {
+                    new_elements.push(self.get((x, y)).clone());
+                } else {
+                    new_elements.push(Cell::default());
+                }
             }
         }
 
@@ -141,13 +567,64 @@ impl Buffer {
         self.cells = new_elements;
     }
 
+    /// Draws this buffer onto another with each cell's colors partway between its own and the destination's,
+    /// where an alpha of 1 is entirely this buffer's colors and 0 is entirely the destination's.
+    /// The glyphs and attributes always come from this buffer.
+    /// Colors without a known RGB value (see `to_rgb`) can't be mixed, so they switch over at an alpha of 0.5.
+    pub fn blend_onto(&self, dst: &mut Buffer, loc: impl Into<Vec2>, alpha: f32) {
+        let loc = loc.into();
+        let fallback =
+            |src: Option<Color>, dst: Option<Color>| if alpha >= 0.5 { src } else { dst };
+        let blend = |src: Option<Color>, dst: Option<Color>| match (src, dst) {
+            (Some(from), Some(to)) => mix(to, from, alpha).or(fallback(src, dst)),
+            _ => fallback(src, dst),
+        };
+
+        let end_x = loc.x.saturating_add(self.size.x).min(dst.size.x);
+        let end_y = loc.y.saturating_add(self.size.y).min(dst.size.y);
+        for y in loc.y..end_y {
+            for x in loc.x..end_x {
+                let mut cell = self.get((x - loc.x, y - loc.y)).clone();
+                let under = *dst.get((x, y)).style();
+
+                let style = cell.style_mut();
+                style.foreground_color = blend(style.foreground_color, under.foreground_color);
+                style.background_color = blend(style.background_color, under.background_color);
+
+                dst.set((x, y), cell);
+            }
+        }
+    }
+
+    /// Renders this buffer onto another, skipping any cells that are still the default cell.
+    /// This lets layered buffers compose, with the destination showing through untouched cells.
+    pub fn render_over(&self, loc: impl Into<Vec2>, buffer: &mut Buffer) -> Vec2 {
+        let loc = loc.into();
+        let default = Cell::default();
+
+        // Only the part that lands inside the destination is drawn.
+        let width = self.size.x.min(buffer.size.x.saturating_sub(loc.x));
+        let height = self.size.y.min(buffer.size.y.saturating_sub(loc.y));
+
+        for x in 0..width {
+            for y in 0..height {
+                let cell = self.get(vec2(x, y));
+                if *cell != default {
+                    buffer.set(vec2(x + loc.x, y + loc.y), cell.clone());
+                }
+            }
+        }
+
+        vec2(
+            loc.x.saturating_add(self.size.x),
+            loc.y.saturating_add(self.size.y),
+        )
+    }
+
     /// Creates a Buffer from the given element with the minimum size it could have for that element.
     /// Useful for if you want to store any set of render elements in a custom element.
     pub fn sized_element<R: Render>(item: R) -> Self {
-        let mut buff = Buffer::new((100, 100));
-        render!(buff, vec2(0, 0) => [ item ]);
-        buff.shrink();
-        buff
+        item.to_buffer()
     }
 }
 
@@ -171,3 +648,607 @@ impl Render for Buffer {
         vec2(loc.x + buffer.size().x, loc.y + buffer.size().y)
     }
 }
+
+/// The number of cells a buffer needs before `fill` splits the work across threads.
+/// Filling takes about 2ns a cell, while starting and joining the threads takes about 35µs for 2 threads
+/// up to 110µs for 8 (measured in release builds), so threads only start paying off between 30,000
+/// and 60,000 cells. Below this, starting the threads costs more than filling the cells.
+#[cfg(feature = "parallel")]
+const PARALLEL_FILL_THRESHOLD: usize = 1 << 16;
+
+/// Fills the cells using one scoped thread per available core, each taking an even share.
+#[cfg(feature = "parallel")]
+fn parallel_fill(cells: &mut [Cell], cell: &Cell) {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    fill_across(cells, cell, threads);
+}
+
+/// Fills the cells using the given number of scoped threads, each taking an even share.
+#[cfg(feature = "parallel")]
+fn fill_across(cells: &mut [Cell], cell: &Cell, threads: usize) {
+    if threads <= 1 {
+        cells.fill(cell.clone());
+        return;
+    }
+
+    let chunk = cells.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        for part in cells.chunks_mut(chunk) {
+            scope.spawn(move || part.fill(cell.clone()));
+        }
+    });
+}
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// Joins the lines along the cells, given in order, wherever a run of glyphs that are just `straight`
+/// lines is entered from both ends, with the first cell gaining a `back` arm and the last a `forward` one.
+fn bridge_runs(
+    cells: &mut [Option<(u8, u8)>],
+    order: &[usize],
+    straight: u8,
+    back: u8,
+    forward: u8,
+) {
+    let mut i = 0;
+    while i < order.len() {
+        let is_straight = |i: usize| matches!(cells[order[i]], Some((own, _)) if own == straight);
+        if !is_straight(i) {
+            i += 1;
+            continue;
+        }
+
+        let first = i;
+        while i + 1 < order.len() && is_straight(i + 1) {
+            i += 1;
+        }
+        let last = i;
+        i += 1;
+
+        let arms = |i: usize| cells[order[i]].map_or(0, |(_, arms)| arms);
+        if first != last && arms(first) & back != 0 && arms(last) & forward != 0 {
+            for &idx in &order[first..=last] {
+                if let Some((_, arms)) = &mut cells[idx] {
+                    *arms |= back | forward;
+                }
+            }
+        }
+    }
+}
+
+/// Returns which directions a light box-drawing glyph has lines in, if it is one.
+fn box_arms(glyph: &str) -> Option<u8> {
+    Some(match glyph {
+        "─" => LEFT | RIGHT,
+        "│" => UP | DOWN,
+        "┌" | "╭" => DOWN | RIGHT,
+        "┐" | "╮" => DOWN | LEFT,
+        "└" | "╰" => UP | RIGHT,
+        "┘" | "╯" => UP | LEFT,
+        "├" => UP | DOWN | RIGHT,
+        "┤" => UP | DOWN | LEFT,
+        "┬" => DOWN | LEFT | RIGHT,
+        "┴" => UP | LEFT | RIGHT,
+        "┼" => UP | DOWN | LEFT | RIGHT,
+        _ => return None,
+    })
+}
+
+/// Returns the light box-drawing glyph with lines in the given directions.
+fn box_glyph(arms: u8) -> &'static str {
+    match arms {
+        a if a == LEFT | RIGHT => "─",
+        a if a == UP | DOWN => "│",
+        a if a == DOWN | RIGHT => "┌",
+        a if a == DOWN | LEFT => "┐",
+        a if a == UP | RIGHT => "└",
+        a if a == UP | LEFT => "┘",
+        a if a == UP | DOWN | RIGHT => "├",
+        a if a == UP | DOWN | LEFT => "┤",
+        a if a == DOWN | LEFT | RIGHT => "┬",
+        a if a == UP | LEFT | RIGHT => "┴",
+        _ => "┼",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_of_differently_sized_buffers_redraws_everything() {
+        let old = Buffer::new((2, 2));
+        let mut new = Buffer::new((3, 1));
+        new.set((1, 0), 'x');
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.len(), 3);
+        assert_eq!(diff[1], (vec2(1, 0), &Cell::chr('x')));
+    }
+
+    #[test]
+    fn row_and_column_yield_cells_in_order() {
+        let mut buffer = Buffer::new((3, 2));
+        buffer.set_str((0, 1), "abc");
+
+        let row: Vec<&str> = buffer.row(1).map(Cell::text).collect();
+        let column: Vec<&str> = buffer.column(2).map(Cell::text).collect();
+
+        assert_eq!(row, ["a", "b", "c"]);
+        assert_eq!(column, [" ", "c"]);
+    }
+
+    #[test]
+    fn growing_resize_keeps_cells_and_blanks_the_rest() {
+        let mut buffer = Buffer::new((2, 2));
+        buffer.set_str((0, 0), "ab");
+        buffer.set_str((0, 1), "cd");
+
+        buffer.resize((4, 4));
+
+        assert_eq!(buffer.size(), vec2(4, 4));
+        assert_eq!(buffer.to_lines(), ["ab", "cd", "", ""]);
+        assert!(buffer
+            .iter()
+            .filter(|(loc, _)| loc.x >= 2 || loc.y >= 2)
+            .all(|(_, cell)| *cell == Cell::default()));
+    }
+
+    #[test]
+    fn blank_region_is_empty() {
+        let mut buffer = Buffer::new((4, 4));
+        buffer.set((3, 3), 'x');
+
+        assert!(buffer.is_region_empty(Rect::new((0, 0), (3, 3))));
+    }
+
+    #[test]
+    fn region_with_one_cell_drawn_is_not_empty() {
+        let mut buffer = Buffer::new((4, 4));
+        buffer.set((1, 2), 'x');
+
+        assert!(!buffer.is_region_empty(Rect::new((0, 0), (3, 3))));
+    }
+
+    #[test]
+    fn zero_size_and_out_of_bounds_regions_are_empty() {
+        let mut buffer = Buffer::new((4, 4));
+        buffer.fill('x');
+
+        assert!(buffer.is_region_empty(Rect::new((1, 1), (0, 0))));
+        assert!(buffer.is_region_empty(Rect::new((10, 10), (2, 2))));
+    }
+
+    #[test]
+    fn trim_crops_leading_blank_space() {
+        let mut buffer = Buffer::new((6, 4));
+        buffer.set_str((3, 2), "ab");
+        buffer.set_str((4, 3), "c");
+
+        let (trimmed, origin) = buffer.trim();
+
+        assert_eq!(origin, vec2(3, 2));
+        assert_eq!(trimmed.size(), vec2(2, 2));
+        assert_eq!(trimmed.to_lines(), ["ab", " c"]);
+    }
+
+    #[test]
+    fn visual_diff_names_the_differing_cell() {
+        let left = Buffer::new((3, 2));
+        let mut right = left.clone();
+        right.set((2, 1), 'x');
+
+        assert_eq!(left.visual_diff(&left), "");
+        assert_eq!(left.visual_diff(&right), "(2, 1): left \" \", right \"x\"");
+    }
+
+    #[test]
+    fn visual_diff_notes_size_mismatches() {
+        let diff = Buffer::new((3, 2)).visual_diff(&Buffer::new((2, 2)));
+
+        assert_eq!(diff, "size mismatch: left is 3x2, right is 2x2");
+    }
+
+    #[test]
+    fn iter_walks_cells_in_row_major_order() {
+        let mut buffer = Buffer::new((2, 2));
+        buffer.set_str((0, 0), "ab");
+        buffer.set_str((0, 1), "cd");
+
+        let cells: Vec<(Vec2, &str)> = buffer
+            .iter()
+            .map(|(loc, cell)| (loc, cell.text()))
+            .collect();
+
+        assert_eq!(
+            cells,
+            [
+                (vec2(0, 0), "a"),
+                (vec2(1, 0), "b"),
+                (vec2(0, 1), "c"),
+                (vec2(1, 1), "d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_mut_edits_cells_in_place() {
+        let mut buffer = Buffer::new((2, 2));
+        for (loc, cell) in buffer.iter_mut() {
+            *cell = Cell::chr(char::from(b'0' + (loc.y * 2 + loc.x) as u8));
+        }
+
+        assert_eq!(buffer.to_lines(), ["01", "23"]);
+    }
+
+    #[test]
+    fn mutating_a_clone_leaves_the_original_alone() {
+        let original = Buffer::new((2, 2));
+        let mut copy = original.clone();
+        copy.set((0, 0), 'x');
+
+        assert_eq!(original.get((0, 0)), &Cell::default());
+        assert_ne!(original, copy);
+    }
+
+    #[test]
+    fn buffers_with_the_same_cells_are_equal() {
+        let mut a = Buffer::new((3, 1));
+        let mut b = Buffer::new((3, 1));
+        a.set_str((0, 0), "abc");
+        b.set_str((0, 0), "abc");
+
+        assert_eq!(a, b);
+        assert_ne!(a, Buffer::new((3, 1)));
+    }
+
+    #[test]
+    fn assert_matches_passes_for_matching_buffers() {
+        let mut buffer = Buffer::new((2, 1));
+        buffer.set((1, 0), 'x');
+
+        buffer.assert_matches(&buffer.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 0): left \"x\", right \" \"")]
+    fn assert_matches_panics_naming_the_differing_cell() {
+        let mut buffer = Buffer::new((2, 1));
+        buffer.set((1, 0), 'x');
+
+        buffer.assert_matches(&Buffer::new((2, 1)));
+    }
+
+    #[test]
+    fn text_views_ignore_styling() {
+        let mut buffer = Buffer::new((6, 2));
+        render!(buffer, vec2(0, 0) => [ "Hello".red().bold() ]);
+        render!(buffer, vec2(1, 1) => [ "hi".on_blue() ]);
+
+        assert_eq!(buffer.text_at((1, 0)), Some("e"));
+        assert_eq!(buffer.text_at((6, 0)), None);
+        assert_eq!(buffer.to_lines(), ["Hello", " hi"]);
+        assert_eq!(buffer.to_plain_string(), "Hello\n hi");
+    }
+
+    #[test]
+    fn set_str_starts_each_line_at_the_same_column() {
+        let mut buffer = Buffer::new((5, 3));
+        let end = buffer.set_str((1, 0), "ab\ncde");
+
+        assert_eq!(end, vec2(4, 1));
+        assert_eq!(buffer.to_lines(), [" ab", " cde", ""]);
+        assert_eq!(buffer.get((3, 1)), &Cell::chr('e'));
+    }
+
+    #[test]
+    fn draw_text_wrapped_wraps_within_a_narrow_rect() {
+        let mut buffer = Buffer::new((8, 4));
+        let rows = buffer.draw_text_wrapped(
+            Rect::new((1, 0), (5, 3)),
+            "the quick brown fox",
+            ContentStyle::default(),
+        );
+
+        assert_eq!(rows, 3);
+        assert_eq!(buffer.to_lines(), [" the", " quick", " brown", ""]);
+    }
+
+    #[test]
+    fn draw_text_wrapped_uses_one_row_for_short_text() {
+        let mut buffer = Buffer::new((8, 2));
+        let style = ContentStyle::new().green();
+        let rows = buffer.draw_text_wrapped(Rect::new((0, 0), (8, 2)), "hi", style);
+
+        assert_eq!(rows, 1);
+        assert_eq!(buffer.to_lines(), ["hi", ""]);
+        assert_eq!(buffer.get((1, 0)).style(), &style);
+    }
+
+    #[test]
+    fn remap_colors_only_changes_mapped_colors() {
+        let mut buffer = Buffer::new((3, 1));
+        buffer.set((0, 0), Cell::new("a", ContentStyle::new().red()));
+        buffer.set((1, 0), Cell::new("b", ContentStyle::new().green().on_red()));
+        buffer.set((2, 0), Cell::new("c", ContentStyle::new().yellow()));
+
+        buffer.remap_colors(&HashMap::from([(Color::Red, Color::Blue)]));
+
+        let colors: Vec<_> = buffer
+            .row(0)
+            .map(|cell| (cell.style().foreground_color, cell.style().background_color))
+            .collect();
+        assert_eq!(
+            colors,
+            [
+                (Some(Color::Blue), None),
+                (Some(Color::Green), Some(Color::Blue)),
+                (Some(Color::Yellow), None),
+            ]
+        );
+    }
+
+    fn two_by_three() -> Buffer {
+        let mut buffer = Buffer::new((2, 3));
+        buffer.set_str((0, 0), "ab\ncd\nef");
+        buffer
+    }
+
+    #[test]
+    fn quarter_turn_swaps_the_dimensions() {
+        let rotated = two_by_three().rotate(1);
+
+        assert_eq!(rotated.size(), vec2(3, 2));
+        assert_eq!(rotated.to_lines(), ["eca", "fdb"]);
+    }
+
+    #[test]
+    fn half_turn_flips_both_ways() {
+        let rotated = two_by_three().rotate(2);
+
+        assert_eq!(rotated.size(), vec2(2, 3));
+        assert_eq!(rotated.to_lines(), ["fe", "dc", "ba"]);
+        assert_eq!(two_by_three().rotate(4), two_by_three());
+    }
+
+    #[test]
+    fn content_hash_follows_the_cells() {
+        let mut buffer = Buffer::new((3, 2));
+        buffer.set_str((0, 0), "abc");
+        let hash = buffer.content_hash();
+
+        assert_eq!(buffer.clone().content_hash(), hash);
+
+        buffer.set((1, 1), 'x');
+        assert_ne!(buffer.content_hash(), hash);
+    }
+
+    #[test]
+    fn cells_are_laid_out_row_major() {
+        let mut buffer = Buffer::new((3, 2));
+        let width = buffer.width() as usize;
+        assert_eq!(buffer.height(), 2);
+
+        buffer.cells_mut()[width + 2] = Cell::chr('x');
+
+        assert_eq!(buffer.get((2, 1)), &Cell::chr('x'));
+        assert_eq!(buffer.cells().len(), 6);
+    }
+
+    #[test]
+    fn blend_onto_mixes_colors_halfway() {
+        let mut red = Buffer::new((2, 1));
+        red.fill(Cell::new(
+            "x",
+            ContentStyle::new().with(rgb(255, 0, 0)).on(rgb(255, 0, 0)),
+        ));
+        let mut blue = Buffer::new((2, 1));
+        blue.fill(Cell::bg(rgb(0, 0, 255)));
+
+        red.blend_onto(&mut blue, (0, 0), 0.5);
+
+        let cell = blue.get((1, 0));
+        assert_eq!(cell.text(), "x");
+        assert_eq!(cell.style().background_color, Some(rgb(128, 0, 128)));
+        // The destination has no foreground to mix with, so the source's wins above half.
+        assert_eq!(cell.style().foreground_color, Some(rgb(255, 0, 0)));
+    }
+
+    #[test]
+    fn render_over_lets_empty_cells_show_through() {
+        let mut top = Buffer::new((3, 1));
+        top.set((0, 0), 'a');
+        top.set((2, 0), 'c');
+
+        let mut dest = Buffer::new((4, 2));
+        render!(dest, vec2(0, 0) => [ "wxyz" ]);
+        let end = top.render_over((1, 0), &mut dest);
+
+        let row: String = (0..4).map(|x| dest.get((x, 0)).text()).collect();
+        assert_eq!(row, "wayc");
+        assert_eq!(end, vec2(4, 1));
+    }
+
+    #[test]
+    fn render_over_clips_to_the_destination() {
+        let mut top = Buffer::new((3, 3));
+        top.fill('#');
+
+        let mut dest = Buffer::new((4, 4));
+        top.render_over((2, 3), &mut dest);
+        let drawn: Vec<Vec2> = dest
+            .iter()
+            .filter(|(_, cell)| !cell.is_empty())
+            .map(|(loc, _)| loc)
+            .collect();
+        assert_eq!(drawn, vec![vec2(2, 3), vec2(3, 3)]);
+
+        // Entirely outside, or far enough out to overflow, draws nothing and doesn't panic.
+        let mut dest = Buffer::new((4, 4));
+        assert_eq!(
+            top.render_over((u16::MAX, u16::MAX), &mut dest),
+            vec2(u16::MAX, u16::MAX)
+        );
+        assert!(dest.iter().all(|(_, cell)| cell.is_empty()));
+    }
+
+    fn box_lines(lines: &[&str]) -> Buffer {
+        let mut buffer = Buffer::new((lines[0].chars().count() as u16, lines.len() as u16));
+        for (y, line) in lines.iter().enumerate() {
+            for (x, chr) in line.chars().enumerate() {
+                if chr != ' ' {
+                    buffer.set((x as u16, y as u16), chr);
+                }
+            }
+        }
+        buffer
+    }
+
+    fn connected(lines: &[&str]) -> Vec<String> {
+        let mut buffer = box_lines(lines);
+        buffer.connect_box_drawing(Rect::new((0, 0), buffer.size()));
+        (0..buffer.size().y)
+            .map(|y| {
+                (0..buffer.size().x)
+                    .map(|x| match buffer.get((x, y)).text() {
+                        "" => " ",
+                        text => text,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crossing_lines_join() {
+        assert_eq!(connected(&[" │ ", "───", " │ "]), [" │ ", "─┼─", " │ "]);
+        assert_eq!(connected(&["───", " │ "]), ["─┬─", " │ "]);
+    }
+
+    #[test]
+    fn lines_are_carried_through_side_by_side_borders() {
+        assert_eq!(
+            connected(&[" ││ ", "─││─", " ││ "]),
+            [" ││ ", "─┼┼─", " ││ "]
+        );
+        assert_eq!(
+            connected(&[" │ ", "─│─", "─│─", " │ "]),
+            [" │ ", "─┼─", "─┼─", " │ "]
+        );
+        assert_eq!(
+            connected(&[" │ ", "───", "───", " │ "]),
+            [" │ ", "─┼─", "─┼─", " │ "]
+        );
+
+        // Only a line that goes all the way through joins them.
+        assert_eq!(
+            connected(&[" ││ ", "─││ ", " ││ "]),
+            [" ││ ", "─┤│ ", " ││ "]
+        );
+        assert_eq!(
+            connected(&["┌┐┌┐", "││││", "└┘└┘"]),
+            ["┌┐┌┐", "││││", "└┘└┘"]
+        );
+    }
+
+    #[test]
+    fn map_style_only_changes_cells_inside_the_rect() {
+        let styled = |buffer: &Buffer| -> Vec<Vec2> {
+            buffer
+                .iter()
+                .filter(|(_, cell)| cell.style().background_color == Some(Color::Red))
+                .map(|(loc, _)| loc)
+                .collect()
+        };
+        let red = |style: &mut ContentStyle| style.background_color = Some(Color::Red);
+
+        let mut buffer = Buffer::new((4, 3));
+        buffer.map_style(Rect::new((1, 1), (2, 1)), red);
+        assert_eq!(styled(&buffer), [vec2(1, 1), vec2(2, 1)]);
+
+        // Anything past the edge of the buffer is dropped.
+        let mut buffer = Buffer::new((4, 3));
+        buffer.map_style(Rect::new((2, 1), (10, 10)), red);
+        assert_eq!(
+            styled(&buffer),
+            [vec2(2, 1), vec2(3, 1), vec2(2, 2), vec2(3, 2)]
+        );
+
+        let mut buffer = Buffer::new((4, 3));
+        buffer.map_style(Rect::new((u16::MAX, 0), (u16::MAX, u16::MAX)), red);
+        assert!(styled(&buffer).is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_fill_matches_the_serial_fill() {
+        let cell = Cell::chr('#').with_bg(Color::Blue);
+
+        for len in [0, 1, 7, 100, 1001] {
+            let mut serial = vec![Cell::chr('.'); len];
+            serial.fill(cell.clone());
+
+            for threads in 1..=5 {
+                let mut parallel = vec![Cell::chr('.'); len];
+                fill_across(&mut parallel, &cell, threads);
+                assert_eq!(parallel, serial, "{len} cells across {threads} threads");
+            }
+        }
+
+        // Past the threshold, filling the buffer goes through the threads.
+        let mut buffer = Buffer::new((256, 257));
+        buffer.fill(cell.clone());
+        assert!(buffer.iter().all(|(_, c)| *c == cell));
+    }
+
+    #[test]
+    fn set_str_gives_wide_characters_two_cells() {
+        let texts = |buffer: &Buffer| -> Vec<String> {
+            (0..buffer.size().x)
+                .map(|x| buffer.get((x, 0)).text().to_string())
+                .collect()
+        };
+
+        let mut buffer = Buffer::new((6, 1));
+        assert_eq!(buffer.set_str((0, 0), "日本x"), vec2(5, 0));
+        assert_eq!(texts(&buffer), ["日", "", "本", "", "x", " "]);
+
+        // A wide character that would hang off the edge is clipped whole.
+        let mut buffer = Buffer::new((3, 1));
+        assert_eq!(buffer.set_str((0, 0), "ab🦀"), vec2(2, 0));
+        assert_eq!(texts(&buffer), ["a", "b", " "]);
+
+        // Combining marks stay with the character they modify.
+        let mut buffer = Buffer::new((3, 1));
+        assert_eq!(buffer.set_str((0, 0), "e\u{301}x"), vec2(2, 0));
+        assert_eq!(texts(&buffer), ["e\u{301}", "x", " "]);
+    }
+
+    #[test]
+    fn dim_darkens_known_colors() {
+        let mut buffer = Buffer::new((3, 1));
+        buffer.set((0, 0), Cell::chr('a').with_bg(Color::White));
+        buffer.set(
+            (1, 0),
+            Cell::new("b", ContentStyle::new().with(rgb(200, 100, 0))),
+        );
+
+        buffer.dim(0.5);
+
+        assert_eq!(
+            buffer.get((0, 0)).style().background_color,
+            Some(rgb(128, 128, 128))
+        );
+        assert_eq!(
+            buffer.get((1, 0)).style().foreground_color,
+            Some(rgb(100, 50, 0))
+        );
+        // Default colors are left to the terminal.
+        assert_eq!(*buffer.get((2, 0)), Cell::default());
+    }
+}
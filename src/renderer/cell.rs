@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use compact_str::{CompactString, ToCompactString};
-use crossterm::style::{ContentStyle, StyledContent};
+use crossterm::style::{Attribute, Color, ContentStyle, StyledContent};
 
 use crate::{math::Vec2, prelude::Render};
 
@@ -47,6 +47,78 @@ impl Cell {
         }
     }
 
+    /// Creates a blank cell with the given background color, useful for painting backgrounds.
+    pub fn bg(color: Color) -> Self {
+        Self::chr(' ').with_bg(color)
+    }
+
+    /// Returns the cell with its background color replaced by the given color.
+    pub fn with_bg(mut self, color: Color) -> Self {
+        self.style.background_color = Some(color);
+        self
+    }
+
+    /// Returns the cell with the given attribute set on its style.
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.style.attributes.set(attribute);
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.attribute(Attribute::Bold)
+    }
+
+    pub fn italic(self) -> Self {
+        self.attribute(Attribute::Italic)
+    }
+
+    pub fn underlined(self) -> Self {
+        self.attribute(Attribute::Underlined)
+    }
+
+    /// Returns the cell underlined in the given style, replacing any other underline style.
+    /// Expects one of `Underlined`, `DoubleUnderlined`, `Undercurled`, `Underdotted`, or `Underdashed`,
+    /// which terminals without styled underlines draw as a plain underline.
+    pub fn underline_style(mut self, style: Attribute) -> Self {
+        for underline in UNDERLINES {
+            self.style.attributes.unset(underline);
+        }
+        self.attribute(style)
+    }
+
+    /// Returns the cell with its underline drawn in the given color, underlining it if it wasn't already.
+    /// Handy for things like spell-check squiggles alongside `underline_style(Attribute::Undercurled)`.
+    pub fn underline_color(mut self, color: Color) -> Self {
+        self.style.underline_color = Some(color);
+        if UNDERLINES.iter().any(|u| self.style.attributes.has(*u)) {
+            self
+        } else {
+            self.underlined()
+        }
+    }
+
+    pub fn reversed(self) -> Self {
+        self.attribute(Attribute::Reverse)
+    }
+
+    pub fn dim(self) -> Self {
+        self.attribute(Attribute::Dim)
+    }
+
+    pub fn blink(self) -> Self {
+        self.attribute(Attribute::SlowBlink)
+    }
+
+    /// Draws a line through the text, like a finished to-do item.
+    pub fn strikethrough(self) -> Self {
+        self.attribute(Attribute::CrossedOut)
+    }
+
+    /// Hides the text while keeping the cell's colors, on terminals that support it.
+    pub fn hidden(self) -> Self {
+        self.attribute(Attribute::Hidden)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.text.trim().is_empty()
     }
@@ -58,8 +130,21 @@ impl Cell {
     pub fn style(&self) -> &ContentStyle {
         &self.style
     }
+
+    pub fn style_mut(&mut self) -> &mut ContentStyle {
+        &mut self.style
+    }
 }
 
+/// Every attribute that draws an underline.
+const UNDERLINES: [Attribute; 5] = [
+    Attribute::Underlined,
+    Attribute::DoubleUnderlined,
+    Attribute::Undercurled,
+    Attribute::Underdotted,
+    Attribute::Underdashed,
+];
+
 impl Render for Cell {
     fn render(&self, loc: crate::prelude::Vec2, buffer: &mut crate::prelude::Buffer) -> Vec2 {
         buffer.set(loc, self.clone());
@@ -98,3 +183,73 @@ impl Display for Cell {
         write!(f, "{}", StyledContent::new(self.style, &self.text))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::text_width;
+
+    #[test]
+    fn bg_is_a_blank_cell_with_a_background() {
+        let cell = Cell::bg(Color::Blue);
+
+        assert_eq!(text_width(cell.text()), 1);
+        assert!(cell.is_empty());
+        assert_eq!(cell.style().background_color, Some(Color::Blue));
+        assert_eq!(cell.style().foreground_color, None);
+    }
+
+    #[test]
+    fn with_bg_recolors_only_the_background() {
+        let cell = Cell::chr('x').with_bg(Color::Red);
+
+        assert_eq!(cell.text(), "x");
+        assert_eq!(cell.style().background_color, Some(Color::Red));
+    }
+
+    #[test]
+    fn attribute_helpers_set_their_attribute() {
+        let cells = [
+            (Cell::chr('x').bold(), Attribute::Bold),
+            (Cell::chr('x').italic(), Attribute::Italic),
+            (Cell::chr('x').underlined(), Attribute::Underlined),
+            (Cell::chr('x').reversed(), Attribute::Reverse),
+            (Cell::chr('x').dim(), Attribute::Dim),
+            (Cell::chr('x').blink(), Attribute::SlowBlink),
+        ];
+
+        for (cell, attribute) in cells {
+            assert!(cell.style().attributes.has(attribute), "{attribute:?}");
+        }
+    }
+
+    #[test]
+    fn strikethrough_and_hidden_reach_the_display_output() {
+        assert!(Cell::chr('x')
+            .strikethrough()
+            .to_string()
+            .contains("\x1b[9m"));
+        assert!(Cell::chr('x').hidden().to_string().contains("\x1b[8m"));
+    }
+
+    #[test]
+    fn underline_color_reaches_the_display_output() {
+        use crossterm::{style::SetUnderlineColor, Command};
+
+        let color = Color::Rgb { r: 255, g: 0, b: 0 };
+        let mut expected = String::new();
+        SetUnderlineColor(color).write_ansi(&mut expected).unwrap();
+
+        let cell = Cell::chr('x').underline_color(color);
+        assert!(cell.to_string().contains(&expected), "{cell}");
+        assert!(cell.style().attributes.has(Attribute::Underlined));
+
+        // An existing underline style is kept rather than replaced.
+        let cell = Cell::chr('x')
+            .underline_style(Attribute::Undercurled)
+            .underline_color(color);
+        assert!(cell.to_string().contains(&expected));
+        assert!(cell.style().attributes.has(Attribute::Undercurled));
+        assert!(!cell.style().attributes.has(Attribute::Underlined));
+    }
+}
@@ -10,17 +10,20 @@ use crate::prelude::*;
 /**
 `Example`
 ```rust, no_run
-use crate::prelude::*;
+use ascii_forge::prelude::*;
 
+# fn main() -> std::io::Result<()> {
 // Create a window
-let window = Window::init()?;
+let mut window = Window::init()?;
 
 // Render This works! and Another Element! To the window's buffer
 render!(
     window,
-        vec2(16, 16) => [ "This works!" ]
+        vec2(16, 16) => [ "This works!" ],
         vec2(0, 0) => [ "Another Element!" ]
 );
+# Ok(())
+# }
 ```
 */
 #[macro_export]
@@ -40,6 +43,170 @@ macro_rules! render {
 /// Render's return type is the location the render ended at.
 pub trait Render {
     fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2;
+
+    /// Renders the element aligned within the rect, clipping anything that doesn't fit.
+    /// The element's size is measured by rendering it into a scratch buffer the size of the rect.
+    /// Returns the location just past the bottom right of the placed element.
+    fn render_in(&self, rect: Rect, align: Alignment, buffer: &mut Buffer) -> Vec2 {
+        // Nothing fits in an empty rect.
+        if rect.size.x == 0 || rect.size.y == 0 {
+            return rect.loc;
+        }
+
+        let mut scratch = Buffer::new(rect.size);
+        self.render(vec2(0, 0), &mut scratch);
+        crop_to_drawn(&mut scratch);
+
+        let size = scratch.size();
+        let loc = vec2(
+            rect.loc.x + align.horizontal.offset(rect.size.x, size.x),
+            rect.loc.y + align.vertical.offset(rect.size.y, size.y),
+        );
+
+        scratch.render(loc, buffer);
+        vec2(loc.x + size.x, loc.y + size.y)
+    }
+
+    /// Renders the part of the element that starts `source_offset` into it, clipped to `clip_size`, at the location.
+    /// Skipping into the element like this is the core of a scrolling view.
+    /// Returns the location just past the bottom right of the clip.
+    fn render_scrolled(
+        &self,
+        loc: Vec2,
+        clip_size: Vec2,
+        source_offset: Vec2,
+        buffer: &mut Buffer,
+    ) -> Vec2 {
+        let mut scratch = Buffer::new((
+            source_offset.x.saturating_add(clip_size.x),
+            source_offset.y.saturating_add(clip_size.y),
+        ));
+        self.render(vec2(0, 0), &mut scratch);
+
+        let end = vec2(
+            loc.x.saturating_add(clip_size.x),
+            loc.y.saturating_add(clip_size.y),
+        );
+        let visible = vec2(
+            (scratch.size().x - source_offset.x).min(buffer.size().x.saturating_sub(loc.x)),
+            (scratch.size().y - source_offset.y).min(buffer.size().y.saturating_sub(loc.y)),
+        );
+        for y in 0..visible.y {
+            for x in 0..visible.x {
+                let cell = scratch.get((source_offset.x + x, source_offset.y + y));
+                buffer.set((loc.x + x, loc.y + y), cell.clone());
+            }
+        }
+        end
+    }
+
+    /// Renders the element into a buffer just large enough to hold it, so it can be stored and drawn again later.
+    /// The scratch buffer starts small and doubles in size while the element reaches its edges,
+    /// up to 1024 cells in each direction.
+    /// Elements that fill whatever they are given, like a `Rule` without a width, have no size of their own,
+    /// so along that axis they are rendered at the starting size of 64 columns or 16 rows.
+    fn to_buffer(&self) -> Buffer {
+        render_to_buffer(self).0
+    }
+}
+
+/// The size `Render::to_buffer` starts its scratch buffer at.
+const START_BUFFER_SIZE: Vec2 = Vec2 { x: 64, y: 16 };
+
+/// The largest width and height `Render::to_buffer` will grow its scratch buffer to.
+const MAX_BUFFER_SIDE: u16 = 1024;
+
+/// Renders the element at the origin of a scratch buffer that grows until the element fits, then crops it.
+/// Returns the cropped buffer along with the location the element's render ended at.
+fn render_to_buffer<R: Render + ?Sized>(element: &R) -> (Buffer, Vec2) {
+    let mut size = START_BUFFER_SIZE;
+    // Set once an axis still reaches the edge at the largest size, meaning the element fills any size it is given.
+    let mut fills_x = false;
+    let mut fills_y = false;
+    loop {
+        let mut buffer = Buffer::new(size);
+        let end = element.render(vec2(0, 0), &mut buffer);
+
+        let reaches_x = !fills_x && buffer.column(size.x - 1).any(is_drawn);
+        let reaches_y = !fills_y && buffer.row(size.y - 1).any(is_drawn);
+
+        if !reaches_x && !reaches_y {
+            crop_to_drawn(&mut buffer);
+            return (buffer, end);
+        }
+        if reaches_x {
+            if size.x < MAX_BUFFER_SIDE {
+                size.x = (size.x * 2).min(MAX_BUFFER_SIDE);
+            } else {
+                fills_x = true;
+                size.x = START_BUFFER_SIZE.x;
+            }
+        }
+        if reaches_y {
+            if size.y < MAX_BUFFER_SIDE {
+                size.y = (size.y * 2).min(MAX_BUFFER_SIDE);
+            } else {
+                fills_y = true;
+                size.y = START_BUFFER_SIZE.y;
+            }
+        }
+    }
+}
+
+/// Whether anything was drawn to the cell, including a background or style on a blank cell.
+fn is_drawn(cell: &Cell) -> bool {
+    *cell != Cell::default()
+}
+
+/// Crops the buffer down to the top left region holding every drawn cell.
+/// Unlike `Buffer::shrink`, cells with only a background or style count as drawn.
+fn crop_to_drawn(buffer: &mut Buffer) {
+    let mut size = vec2(0, 0);
+    for y in 0..buffer.size().y {
+        for x in 0..buffer.size().x {
+            if is_drawn(buffer.get((x, y))) {
+                size = vec2(size.x.max(x + 1), size.y.max(y + 1));
+            }
+        }
+    }
+    buffer.resize(size);
+}
+
+/// Where to place something along a single axis.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl Align {
+    /// Returns how far into the available space something of the given size should be placed.
+    pub fn offset(&self, available: u16, size: u16) -> u16 {
+        let free = available.saturating_sub(size);
+        match self {
+            Align::Start => 0,
+            Align::Center => free / 2,
+            Align::End => free,
+        }
+    }
+}
+
+/// Where to place something within a rect, horizontally and vertically.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub horizontal: Align,
+    pub vertical: Align,
+}
+
+impl Alignment {
+    pub fn new(horizontal: Align, vertical: Align) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
 }
 
 /* --------------- Implementations --------------- */
@@ -62,6 +229,35 @@ impl<R: Render + 'static> From<R> for Box<dyn Render> {
     }
 }
 
+/// Boxes the element, so that different kinds of elements can be stored together.
+pub fn boxed(element: impl Render + 'static) -> Box<dyn Render> {
+    Box::new(element)
+}
+
+/// A macro to build a list of different kinds of elements, boxing each one.
+/// Returns a `Vec<Box<dyn Render>>`.
+/**
+`Example`
+```rust
+use ascii_forge::prelude::*;
+
+let list = render_list!["Hello", '!'];
+assert_eq!(list.len(), 2);
+
+let mut buffer = Buffer::new((10, 1));
+let mut loc = vec2(0, 0);
+for item in &list {
+    loc = item.render(loc, &mut buffer);
+}
+```
+*/
+#[macro_export]
+macro_rules! render_list {
+    ($($render:expr),* $(,)?) => {
+        vec![$($crate::prelude::boxed($render)),*] as Vec<Box<dyn $crate::prelude::Render>>
+    };
+}
+
 impl<R: Into<Box<dyn Render>> + Clone> Render for Vec<R> {
     fn render(&self, mut loc: Vec2, buffer: &mut Buffer) -> Vec2 {
         let items: Vec<Box<dyn Render>> = self.iter().map(|x| x.clone().into()).collect();
@@ -72,6 +268,132 @@ impl<R: Into<Box<dyn Render>> + Clone> Render for Vec<R> {
     }
 }
 
+/// Renders every item of the iterator in sequence, each starting where the last one ended, like a Vec.
+/// Works on any iterator of renderable items, without collecting or boxing them first.
+/// Returns the location the last item finished rendering at.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let mut buffer = Buffer::new((10, 1));
+
+render_iter(&mut buffer, vec2(0, 0), (0..3).map(|i| i.to_string()));
+```
+*/
+pub fn render_iter<R: Render>(
+    buffer: &mut Buffer,
+    mut loc: Vec2,
+    iter: impl IntoIterator<Item = R>,
+) -> Vec2 {
+    for item in iter {
+        loc = item.render(loc, buffer);
+    }
+    loc
+}
+
+/// A Render type that renders anything that can be displayed, like numbers, as the text it formats to.
+/// See `disp` for a shorter way to make one.
+pub struct Displayed<T: Display>(pub T);
+
+impl<T: Display> Render for Displayed<T> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        render!(buffer, loc => [ self.0.to_string() ])
+    }
+}
+
+/// Wraps the value so that it renders as the text it displays as.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let score = 42;
+let mut buffer = Buffer::new((20, 1));
+
+render!(buffer, vec2(0, 0) => [ "Score: ", disp(score) ]);
+```
+*/
+pub fn disp<T: Display>(value: T) -> Displayed<T> {
+    Displayed(value)
+}
+
+/// A Render type that renders each of its items on its own row, all starting from the same column.
+/// Unlike a Vec, which continues each item from where the last one ended.
+pub struct Lines<R: Render>(pub Vec<R>);
+
+impl<R: Render> Render for Lines<R> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let mut end = loc;
+        let mut y = loc.y;
+        for line in &self.0 {
+            end = line.render(vec2(loc.x, y), buffer);
+            y = end.y + 1;
+        }
+        end
+    }
+}
+
+/// A Render type that renders its element once up front, then reuses the result.
+/// Rendering copies the stored buffer instead of running the element again,
+/// which makes complex static elements cheap to draw every frame.
+/// To pick up changes to the element, construct a new Cached.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let banner = Cached::new(Lines(vec!["Welcome", "to the game"]));
+let mut buffer = Buffer::new((20, 2));
+
+render!(buffer, vec2(0, 0) => [ banner ]);
+```
+*/
+pub struct Cached<R: Render> {
+    element: R,
+    buffer: Buffer,
+    end: Vec2,
+}
+
+impl<R: Render> Cached<R> {
+    /// Renders the element into the smallest buffer that holds it, and stores that.
+    pub fn new(element: R) -> Self {
+        let (buffer, end) = render_to_buffer(&element);
+        Self {
+            element,
+            buffer,
+            end,
+        }
+    }
+
+    /// The size of the cached render.
+    pub fn size(&self) -> Vec2 {
+        self.buffer.size()
+    }
+
+    /// The cached render of the element.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// The element that was cached.
+    pub fn element(&self) -> &R {
+        &self.element
+    }
+
+    /// Returns the element, dropping the cached render.
+    pub fn into_inner(self) -> R {
+        self.element
+    }
+}
+
+impl<R: Render> Render for Cached<R> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        self.buffer.render(loc, buffer);
+        vec2(loc.x + self.end.x, loc.y + self.end.y)
+    }
+}
+
 /// A Render type that doesn't get split. It purely renders the one item to the screen.
 /// Useful for multi-character emojis.
 pub struct CharString<D: Display, F: Into<StyledContent<D>> + Clone> {
@@ -94,6 +416,41 @@ impl<D: Display, F: Into<StyledContent<D>> + Clone> Render for CharString<D, F>
     }
 }
 
+/// A Render type that fills a block of the given size with a single cell.
+/// Useful for painting backgrounds without leaving the render! macro.
+pub struct Fill {
+    pub cell: Cell,
+    pub size: Vec2,
+}
+
+impl Fill {
+    pub fn new(cell: impl Into<Cell>, size: impl Into<Vec2>) -> Self {
+        Self {
+            cell: cell.into(),
+            size: size.into(),
+        }
+    }
+}
+
+impl Render for Fill {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let end_x = loc.x.saturating_add(self.size.x).min(buffer.size().x);
+        let end_y = loc.y.saturating_add(self.size.y).min(buffer.size().y);
+
+        for y in loc.y..end_y {
+            for x in loc.x..end_x {
+                buffer.set((x, y), self.cell.clone());
+            }
+        }
+
+        // Like text, ends just past the last cell of the bottom row, never past the right edge.
+        vec2(
+            end_x.max(loc.x),
+            loc.y.saturating_add(self.size.y.saturating_sub(1)),
+        )
+    }
+}
+
 impl Render for String {
     fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
         render!(buffer, loc => [ self.as_str() ])
@@ -105,13 +462,221 @@ impl<D: Display> Render for StyledContent<D> {
         let base_x = loc.x;
         for line in format!("{}", self.content()).split('\n') {
             loc.x = base_x;
-            for char in line.chars().collect::<Vec<char>>() {
-                buffer.set(loc, StyledContent::new(*self.style(), char));
-                loc.x += 1;
+            if loc.y < buffer.size().y {
+                for char in line.chars() {
+                    // Anything past the right edge would be clipped, and could overflow the location.
+                    if loc.x >= buffer.size().x {
+                        break;
+                    }
+                    buffer.set(loc, StyledContent::new(*self.style(), char));
+                    loc.x += 1;
+                }
             }
-            loc.y += 1;
+            loc.y = loc.y.saturating_add(1);
         }
-        loc.y -= 1;
+        loc.y = loc.y.saturating_sub(1);
         loc
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell as Counter;
+
+    use super::*;
+
+    struct Counted<'a>(&'a Counter<u32>);
+
+    impl Render for Counted<'_> {
+        fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+            self.0.set(self.0.get() + 1);
+            render!(buffer, loc => [ "counted" ])
+        }
+    }
+
+    #[test]
+    fn cached_renders_its_element_once() {
+        let count = Counter::new(0);
+        let cached = Cached::new(Counted(&count));
+
+        let mut buffer = Buffer::new((10, 3));
+        for y in 0..3 {
+            render!(buffer, vec2(0, y) => [ cached ]);
+        }
+
+        assert_eq!(count.get(), 1);
+        assert_eq!(cached.size(), vec2(7, 1));
+        assert_eq!(buffer.to_lines(), vec!["counted"; 3]);
+    }
+
+    #[test]
+    fn cached_keeps_trailing_blanks_in_its_end() {
+        let mut buffer = Buffer::new((10, 1));
+        let end = render!(buffer, vec2(0, 0) => [ Cached::new("Score: "), disp(42) ]);
+
+        assert_eq!(end, vec2(9, 0));
+        assert_eq!(buffer.to_plain_string(), "Score: 42");
+    }
+
+    #[test]
+    fn to_buffer_matches_the_element_size() {
+        let board = Grid::<char>::new((3, 2), (2, 1)).with_lines(ContentStyle::default());
+
+        assert_eq!(board.to_buffer().size(), board.size());
+    }
+
+    #[test]
+    fn to_buffer_keeps_background_only_cells() {
+        let fill = Fill::new(Cell::bg(Color::Blue), (5, 2));
+
+        assert_eq!(fill.to_buffer().size(), vec2(5, 2));
+        assert_eq!(Cached::new(fill).size(), vec2(5, 2));
+    }
+
+    #[test]
+    fn to_buffer_gives_fill_to_edge_elements_the_starting_width() {
+        assert_eq!(Rule::new().to_buffer().size(), vec2(64, 1));
+        assert_eq!(Rule::new().with_width(10).to_buffer().size(), vec2(10, 1));
+    }
+
+    #[test]
+    fn render_in_places_background_only_cells() {
+        let mut buffer = Buffer::new((6, 4));
+        Fill::new(Cell::bg(Color::Blue), (2, 2)).render_in(
+            Rect::new((0, 0), (6, 4)),
+            Alignment::new(Align::End, Align::End),
+            &mut buffer,
+        );
+
+        assert_eq!(*buffer.get((4, 2)), Cell::bg(Color::Blue));
+        assert_eq!(*buffer.get((3, 1)), Cell::default());
+    }
+
+    #[test]
+    fn render_in_centers_in_the_rect() {
+        let mut buffer = Buffer::new((7, 5));
+        let end = "ab".render_in(
+            Rect::new((1, 1), (6, 3)),
+            Alignment::new(Align::Center, Align::Center),
+            &mut buffer,
+        );
+
+        assert_eq!(end, vec2(5, 3));
+        assert_eq!(buffer.to_lines()[2], "   ab");
+    }
+
+    #[test]
+    fn render_in_places_at_the_bottom_right() {
+        let mut buffer = Buffer::new((6, 4));
+        let end = "ab".render_in(
+            Rect::new((0, 0), (6, 4)),
+            Alignment::new(Align::End, Align::End),
+            &mut buffer,
+        );
+
+        assert_eq!(end, vec2(6, 4));
+        assert_eq!(buffer.to_lines()[3], "    ab");
+    }
+
+    #[test]
+    fn render_in_an_empty_rect_draws_nothing() {
+        let mut buffer = Buffer::new((4, 4));
+        buffer.set((2, 2), 'x');
+
+        let end = "ab".render_in(
+            Rect::new((2, 2), (0, 0)),
+            Alignment::new(Align::Start, Align::Start),
+            &mut buffer,
+        );
+
+        assert_eq!(end, vec2(2, 2));
+        assert_eq!(buffer.get((2, 2)).text(), "x");
+    }
+
+    #[test]
+    fn lines_stack_on_consecutive_rows() {
+        let mut buffer = Buffer::new((8, 5));
+        let end = render!(buffer, vec2(2, 1) => [ Lines(vec!["one", "two", "three"]) ]);
+
+        assert_eq!(end, vec2(7, 3));
+        assert_eq!(buffer.to_lines(), ["", "  one", "  two", "  three", ""]);
+    }
+
+    #[test]
+    fn long_lines_are_clipped_at_the_right_edge() {
+        let long = "x".repeat(u16::MAX as usize + 10);
+        let mut buffer = Buffer::new((4, 2));
+
+        render!(buffer, vec2(2, 0) => [ long.as_str() ]);
+        render!(buffer, vec2(u16::MAX - 1, 1) => [ long.as_str() ]);
+
+        assert_eq!(buffer.to_lines(), ["  xx", ""]);
+    }
+
+    #[test]
+    fn render_iter_threads_the_location() {
+        let mut buffer = Buffer::new((10, 1));
+        let end = render_iter(&mut buffer, vec2(1, 0), (0..3).map(|i| i.to_string()));
+
+        assert_eq!(end, vec2(4, 0));
+        assert_eq!(buffer.to_lines(), [" 012"]);
+    }
+
+    #[test]
+    fn disp_spells_out_the_formatted_value() {
+        let mut buffer = Buffer::new((4, 1));
+        let end = render!(buffer, vec2(1, 0) => [ disp(42) ]);
+
+        assert_eq!(end, vec2(3, 0));
+        assert_eq!(buffer.text_at((1, 0)), Some("4"));
+        assert_eq!(buffer.text_at((2, 0)), Some("2"));
+    }
+
+    #[test]
+    fn render_scrolled_skips_into_the_element() {
+        let mut buffer = Buffer::new((6, 4));
+        let end = Lines(vec!["one", "two", "three", "four"]).render_scrolled(
+            vec2(1, 1),
+            vec2(4, 2),
+            vec2(0, 1),
+            &mut buffer,
+        );
+
+        assert_eq!(end, vec2(5, 3));
+        assert_eq!(buffer.to_lines(), ["", " two", " thre", ""]);
+    }
+
+    #[test]
+    fn fill_sets_exactly_its_cells() {
+        let mut buffer = Buffer::new((6, 4));
+        let end = render!(buffer, vec2(1, 1) => [ Fill::new('.', (3, 2)) ]);
+
+        let filled: Vec<Vec2> = buffer
+            .iter()
+            .filter(|(_, cell)| cell.text() == ".")
+            .map(|(loc, _)| loc)
+            .collect();
+        assert_eq!(filled.len(), 6);
+        assert!(filled
+            .iter()
+            .all(|loc| (1..4).contains(&loc.x) && (1..3).contains(&loc.y)));
+        assert_eq!(end, vec2(4, 2));
+    }
+
+    #[test]
+    fn fill_end_is_clipped_and_saturates() {
+        let mut buffer = Buffer::new((4, 4));
+        assert_eq!(
+            render!(buffer, vec2(2, 3) => [ Fill::new('.', (5, 5)) ]),
+            vec2(4, 7)
+        );
+        assert_eq!(
+            render!(buffer, vec2(1, u16::MAX - 1) => [ Fill::new('.', (u16::MAX, u16::MAX)) ]),
+            vec2(4, u16::MAX)
+        );
+        assert_eq!(
+            render!(buffer, vec2(2, 2) => [ Fill::new('.', (0, 0)) ]),
+            vec2(2, 2)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crossterm::style::Color;
+
+/// Creates a truecolor Color from its red, green, and blue components.
+pub fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::Rgb { r, g, b }
+}
+
+/// Creates a truecolor grey, where 0 is black and 255 is white.
+pub fn gray(level: u8) -> Color {
+    rgb(level, level, level)
+}
+
+/// Creates a Color from an index into the 256 color ansi palette.
+pub fn ansi256(idx: u8) -> Color {
+    Color::AnsiValue(idx)
+}
+
+/// Parses a hex color in the form `#rrggbb` or `#rgb`, the leading `#` being optional.
+/// Returns None if the string isn't a valid hex color.
+pub fn hex(text: &str) -> Option<Color> {
+    let digits = text.strip_prefix('#').unwrap_or(text);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel =
+        |idx: usize, len: usize| u8::from_str_radix(&digits[idx * len..(idx + 1) * len], 16);
+
+    match digits.len() {
+        6 => Some(rgb(
+            channel(0, 2).ok()?,
+            channel(1, 2).ok()?,
+            channel(2, 2).ok()?,
+        )),
+        // Short form doubles each digit, so #f80 is #ff8800
+        3 => Some(rgb(
+            channel(0, 1).ok()? * 17,
+            channel(1, 1).ok()? * 17,
+            channel(2, 1).ok()? * 17,
+        )),
+        _ => None,
+    }
+}
+
+/// Returns the red, green, and blue components of the color, using the usual xterm values for named colors.
+/// Returns None for `Color::Reset`, as the terminal's default color can't be known.
+pub fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    Some(match color {
+        Color::Reset => return None,
+        Color::Black => (0, 0, 0),
+        Color::DarkRed => (128, 0, 0),
+        Color::DarkGreen => (0, 128, 0),
+        Color::DarkYellow => (128, 128, 0),
+        Color::DarkBlue => (0, 0, 128),
+        Color::DarkMagenta => (128, 0, 128),
+        Color::DarkCyan => (0, 128, 128),
+        Color::Grey => (192, 192, 192),
+        Color::DarkGrey => (128, 128, 128),
+        Color::Red => (255, 0, 0),
+        Color::Green => (0, 255, 0),
+        Color::Yellow => (255, 255, 0),
+        Color::Blue => (0, 0, 255),
+        Color::Magenta => (255, 0, 255),
+        Color::Cyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::AnsiValue(idx) => match idx {
+            0..=15 => {
+                const BASIC: [Color; 16] = [
+                    Color::Black,
+                    Color::DarkRed,
+                    Color::DarkGreen,
+                    Color::DarkYellow,
+                    Color::DarkBlue,
+                    Color::DarkMagenta,
+                    Color::DarkCyan,
+                    Color::Grey,
+                    Color::DarkGrey,
+                    Color::Red,
+                    Color::Green,
+                    Color::Yellow,
+                    Color::Blue,
+                    Color::Magenta,
+                    Color::Cyan,
+                    Color::White,
+                ];
+                return to_rgb(BASIC[idx as usize]);
+            }
+            16..=231 => {
+                const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+                let idx = idx - 16;
+                (
+                    LEVELS[(idx / 36) as usize],
+                    LEVELS[(idx / 6 % 6) as usize],
+                    LEVELS[(idx % 6) as usize],
+                )
+            }
+            _ => {
+                let level = 8 + 10 * (idx - 232);
+                (level, level, level)
+            }
+        },
+    })
+}
+
+/// Mixes two colors, where a `t` of 0 is entirely `from` and 1 is entirely `to`.
+/// Returns None if either color has no known RGB value (see `to_rgb`).
+pub fn mix(from: Color, to: Color, t: f32) -> Option<Color> {
+    let (from, to) = (to_rgb(from)?, to_rgb(to)?);
+    let t = t.clamp(0.0, 1.0);
+    let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+
+    Some(rgb(
+        channel(from.0, to.0),
+        channel(from.1, to.1),
+        channel(from.2, to.2),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_parses_the_long_form() {
+        assert_eq!(hex("#ff8800"), Some(rgb(255, 136, 0)));
+        assert_eq!(hex("00FF7f"), Some(rgb(0, 255, 127)));
+    }
+
+    #[test]
+    fn hex_parses_the_short_form() {
+        assert_eq!(hex("#f80"), Some(rgb(255, 136, 0)));
+    }
+
+    #[test]
+    fn hex_rejects_invalid_input() {
+        assert_eq!(hex(""), None);
+        assert_eq!(hex("#ff88"), None);
+        assert_eq!(hex("#gg8800"), None);
+        assert_eq!(hex("#ff880é"), None);
+    }
+
+    #[test]
+    fn to_rgb_knows_named_and_ansi_colors() {
+        assert_eq!(to_rgb(Color::Reset), None);
+        assert_eq!(to_rgb(Color::DarkRed), Some((128, 0, 0)));
+        assert_eq!(to_rgb(Color::Cyan), Some((0, 255, 255)));
+        assert_eq!(to_rgb(rgb(1, 2, 3)), Some((1, 2, 3)));
+
+        // The first 16 are the named colors, then a 6x6x6 cube, then a grey ramp.
+        assert_eq!(to_rgb(ansi256(1)), to_rgb(Color::DarkRed));
+        assert_eq!(to_rgb(ansi256(15)), to_rgb(Color::White));
+        assert_eq!(to_rgb(ansi256(16)), Some((0, 0, 0)));
+        assert_eq!(to_rgb(ansi256(196)), Some((255, 0, 0)));
+        assert_eq!(to_rgb(ansi256(110)), Some((135, 175, 215)));
+        assert_eq!(to_rgb(ansi256(232)), Some((8, 8, 8)));
+        assert_eq!(to_rgb(ansi256(255)), Some((238, 238, 238)));
+    }
+
+    #[test]
+    fn mix_blends_between_the_colors() {
+        let (from, to) = (rgb(0, 100, 255), rgb(255, 0, 55));
+
+        assert_eq!(mix(from, to, 0.0), Some(from));
+        assert_eq!(mix(from, to, 1.0), Some(to));
+        assert_eq!(mix(from, to, 0.5), Some(rgb(128, 50, 155)));
+
+        // Out of range amounts are clamped, and unknown colors can't be mixed.
+        assert_eq!(mix(from, to, 2.0), Some(to));
+        assert_eq!(mix(from, Color::Reset, 0.5), None);
+    }
+}
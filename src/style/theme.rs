@@ -0,0 +1,77 @@
+use crossterm::style::{Color, ContentStyle, Stylize};
+
+/// A set of named styles, so the look of an application is decided in one place.
+/// Defaults to the dark theme. Widgets pick up a theme through their `with_theme` builder.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let theme = Theme::default();
+
+// A cell styled as the current selection
+let cell = Cell::new("x", theme.selected);
+
+let rule = Rule::new().with_label("Settings").with_theme(&theme);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub primary: ContentStyle,
+    pub accent: ContentStyle,
+    pub border: ContentStyle,
+    pub selected: ContentStyle,
+    pub muted: ContentStyle,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// A theme meant for terminals with a dark background.
+    pub fn dark() -> Self {
+        Self {
+            primary: ContentStyle::new().with(Color::White),
+            accent: ContentStyle::new().with(Color::Cyan),
+            border: ContentStyle::new().with(Color::DarkGrey),
+            selected: ContentStyle::new().with(Color::Black).on(Color::Cyan),
+            muted: ContentStyle::new().with(Color::DarkGrey),
+        }
+    }
+
+    /// A theme meant for terminals with a light background.
+    pub fn light() -> Self {
+        Self {
+            primary: ContentStyle::new().with(Color::Black),
+            accent: ContentStyle::new().with(Color::DarkBlue),
+            border: ContentStyle::new().with(Color::Grey),
+            selected: ContentStyle::new().with(Color::White).on(Color::DarkBlue),
+            muted: ContentStyle::new().with(Color::Grey),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn selected_style_applies_to_a_cell() {
+        for (theme, fg, bg) in [
+            (Theme::dark(), Color::Black, Color::Cyan),
+            (Theme::light(), Color::White, Color::DarkBlue),
+        ] {
+            let cell = Cell::new("x", theme.selected);
+
+            assert_eq!(cell.style().foreground_color, Some(fg));
+            assert_eq!(cell.style().background_color, Some(bg));
+            assert_eq!(cell.style().underline_color, None);
+            assert!(cell.style().attributes.is_empty());
+        }
+        assert_eq!(Theme::default(), Theme::dark());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::math::{vec2, Vec2};
+
+/// Returns the number of terminal columns the widest line of the text takes up.
+/// Wide characters (such as CJK and most emoji) count as two columns, and combining marks as none.
+pub fn text_width(text: &str) -> u16 {
+    text.split('\n').map(line_width).max().unwrap_or(0)
+}
+
+/// Returns the number of columns and rows the text takes up, accounting for newlines.
+pub fn text_size(text: &str) -> Vec2 {
+    vec2(text_width(text), text.split('\n').count() as u16)
+}
+
+/// Returns the number of columns a single character takes up in the terminal.
+///
+/// This covers the common wide and zero width ranges, not the complete Unicode tables,
+/// so some rarer characters may be measured as a single column.
+pub fn char_width(chr: char) -> u16 {
+    let c = chr as u32;
+
+    let zero_width = c < 0x20
+        || (0x7f..0xa0).contains(&c)
+        || (0x300..=0x36f).contains(&c)
+        || (0x1ab0..=0x1aff).contains(&c)
+        || (0x1dc0..=0x1dff).contains(&c)
+        || (0x200b..=0x200f).contains(&c)
+        || (0x20d0..=0x20ff).contains(&c)
+        || (0xfe00..=0xfe0f).contains(&c)
+        || (0xfe20..=0xfe2f).contains(&c);
+    if zero_width {
+        return 0;
+    }
+
+    let wide = (0x1100..=0x115f).contains(&c)
+        || (0x2e80..=0x303e).contains(&c)
+        || (0x3041..=0x33ff).contains(&c)
+        || (0x3400..=0x4dbf).contains(&c)
+        || (0x4e00..=0x9fff).contains(&c)
+        || (0xa000..=0xa4cf).contains(&c)
+        || (0xac00..=0xd7a3).contains(&c)
+        || (0xf900..=0xfaff).contains(&c)
+        || (0xfe30..=0xfe4f).contains(&c)
+        || (0xff00..=0xff60).contains(&c)
+        || (0xffe0..=0xffe6).contains(&c)
+        || (0x1f300..=0x1f64f).contains(&c)
+        || (0x1f900..=0x1f9ff).contains(&c)
+        || (0x20000..=0x3fffd).contains(&c);
+    if wide {
+        2
+    } else {
+        1
+    }
+}
+
+fn line_width(line: &str) -> u16 {
+    // Summed wider than a u16, so a very long line saturates instead of overflowing.
+    let width: usize = line.chars().map(|chr| char_width(chr) as usize).sum();
+    width.min(u16::MAX as usize) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_characters_take_two_columns() {
+        assert_eq!(text_width("日本"), 4);
+        assert_eq!(text_width("a日"), 3);
+    }
+
+    #[test]
+    fn combining_marks_take_no_columns() {
+        assert_eq!(text_width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn multi_line_text_is_as_wide_as_its_widest_line() {
+        assert_eq!(text_size("ab\nabcd\n"), vec2(4, 3));
+        assert_eq!(text_size(""), vec2(0, 1));
+    }
+
+    #[test]
+    fn very_long_lines_saturate() {
+        let line = "a".repeat(70_000);
+        assert_eq!(text_width(&line), u16::MAX);
+
+        let wide = "日".repeat(40_000);
+        assert_eq!(text_width(&wide), u16::MAX);
+        assert_eq!(text_width(&"日".repeat(1000)), 2000);
+    }
+}
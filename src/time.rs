@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+/// `tick` returns true once the duration has passed, carrying any overshoot into the next countdown.
+/**
+`Example`
+```rust, no_run
+use std::time::{Duration, Instant};
+
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let mut spawner = Timer::new(Duration::from_secs(2));
+let mut last = Instant::now();
+
+loop {
+    window.update(Duration::from_millis(16))?;
+
+    let delta = last.elapsed();
+    last = Instant::now();
+
+    if spawner.tick(delta) {
+        // Spawn something
+    }
+}
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timer {
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl Timer {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Advances the timer by the delta, returning true if the duration has passed.
+    /// Any time past the duration carries over into the next countdown, so the timer doesn't drift.
+    /// A delta spanning several durations still fires only once.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        self.elapsed = self.elapsed.saturating_add(delta);
+        if self.elapsed < self.duration {
+            return false;
+        }
+
+        self.elapsed = if self.duration.is_zero() {
+            Duration::ZERO
+        } else {
+            let overshoot = self.elapsed.as_nanos() % self.duration.as_nanos();
+            Duration::from_nanos(overshoot as u64)
+        };
+        true
+    }
+
+    /// Returns how far through the duration the timer is, from 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// Returns the time left until the timer fires.
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Starts the countdown over, without firing.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fires_once_the_duration_passes() {
+        let mut timer = Timer::new(Duration::from_millis(100));
+
+        assert!(!timer.tick(Duration::from_millis(60)));
+        assert!((timer.progress() - 0.6).abs() < 1e-6);
+        assert!(timer.tick(Duration::from_millis(40)));
+        assert_eq!(timer.remaining(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn carries_overshoot_into_the_next_countdown() {
+        let mut timer = Timer::new(Duration::from_millis(100));
+
+        let fired = (0..100)
+            .filter(|_| timer.tick(Duration::from_millis(30)))
+            .count();
+
+        assert_eq!(fired, 30);
+    }
+
+    #[test]
+    fn long_delta_fires_once_and_keeps_the_remainder() {
+        let mut timer = Timer::new(Duration::from_millis(100));
+
+        assert!(timer.tick(Duration::from_millis(350)));
+        assert_eq!(timer.remaining(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn reset_starts_over_without_firing() {
+        let mut timer = Timer::new(Duration::from_millis(100));
+        timer.tick(Duration::from_millis(90));
+        timer.reset();
+
+        assert!(!timer.tick(Duration::from_millis(90)));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+/// Reveals `to` over `from` from left to right, returning the composited buffer for the given progress.
+/// A progress of 0 is entirely `from`, and 1 is entirely `to`.
+///
+/// Panics if the buffers are not the same size.
+pub fn wipe(from: &Buffer, to: &Buffer, progress: f32) -> Buffer {
+    assert_eq!(
+        from.size(),
+        to.size(),
+        "Transition buffers must be the same size"
+    );
+
+    let size = from.size();
+    let edge = (size.x as f32 * progress.clamp(0.0, 1.0)).round() as u16;
+
+    compose(from, to, |loc| loc.x < edge)
+}
+
+/// Replaces the cells of `from` with those of `to` in a scattered but fixed order,
+/// returning the composited buffer for the given progress.
+/// A progress of 0 is entirely `from`, and 1 is entirely `to`.
+///
+/// Panics if the buffers are not the same size.
+pub fn dissolve(from: &Buffer, to: &Buffer, progress: f32) -> Buffer {
+    assert_eq!(
+        from.size(),
+        to.size(),
+        "Transition buffers must be the same size"
+    );
+
+    let size = from.size();
+    let progress = progress.clamp(0.0, 1.0);
+
+    compose(from, to, |loc| {
+        let idx = loc.y as u32 * size.x as u32 + loc.x as u32;
+        threshold(idx) < progress
+    })
+}
+
+/// Builds a buffer taking each cell from `to` where `use_to` is true, and from `from` elsewhere.
+fn compose(from: &Buffer, to: &Buffer, use_to: impl Fn(Vec2) -> bool) -> Buffer {
+    let size = from.size();
+    let mut res = Buffer::new(size);
+
+    for y in 0..size.y {
+        for x in 0..size.x {
+            let loc = vec2(x, y);
+            let cell = if use_to(loc) {
+                to.get(loc)
+            } else {
+                from.get(loc)
+            };
+            res.set(loc, cell.clone());
+        }
+    }
+
+    res
+}
+
+/// Scrambles a cell index into a value in [0, 1), giving each cell a fixed point at which it dissolves.
+fn threshold(idx: u32) -> f32 {
+    let mut n = idx.wrapping_mul(0x9e37_79b9);
+    n ^= n >> 16;
+    n = n.wrapping_mul(0x85eb_ca6b);
+    n ^= n >> 13;
+
+    (n >> 8) as f32 / (1 << 24) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(chr: char) -> Buffer {
+        let mut buffer = Buffer::new((4, 2));
+        buffer.fill(chr);
+        buffer
+    }
+
+    #[test]
+    fn start_is_from_and_end_is_to() {
+        let (from, to) = (filled('a'), filled('b'));
+
+        assert_eq!(wipe(&from, &to, 0.0), from);
+        assert_eq!(wipe(&from, &to, 1.0), to);
+        assert_eq!(dissolve(&from, &to, 0.0), from);
+        assert_eq!(dissolve(&from, &to, 1.0), to);
+    }
+
+    #[test]
+    fn wipe_halfway_splits_at_the_middle_column() {
+        let buffer = wipe(&filled('a'), &filled('b'), 0.5);
+
+        assert_eq!(buffer.to_lines(), ["bbaa", "bbaa"]);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::prelude::*;
+
+/// A toggleable form control, rendered as `[x] label` when checked and `[ ] label` when not.
+/// Space and Enter flip it through `handle_key`, and a left click within `rect` flips it through `update`.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let mut checkbox = Checkbox::new("Enable sound", Rect::new((0, 0), (16, 1)));
+
+checkbox.update(&window);
+render!(window, checkbox.rect.loc => [ checkbox ]);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkbox {
+    pub label: String,
+    pub checked: bool,
+    pub rect: Rect,
+    pub box_style: ContentStyle,
+    pub label_style: ContentStyle,
+}
+
+impl Checkbox {
+    pub fn new(label: impl Into<String>, rect: Rect) -> Self {
+        Self {
+            label: label.into(),
+            checked: false,
+            rect,
+            box_style: ContentStyle::default(),
+            label_style: ContentStyle::default(),
+        }
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn with_box_style(mut self, style: ContentStyle) -> Self {
+        self.box_style = style;
+        self
+    }
+
+    pub fn with_label_style(mut self, style: ContentStyle) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Styles the box with the theme's accent, and the label with its primary style.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.box_style = theme.accent;
+        self.label_style = theme.primary;
+        self
+    }
+
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+
+    /// Toggles the checkbox on Space or Enter, returning true if it was toggled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let toggles = matches!(key.code, KeyCode::Char(' ') | KeyCode::Enter)
+            && key.kind != KeyEventKind::Release;
+        if toggles {
+            self.toggle();
+        }
+        toggles
+    }
+
+    /// Toggles the checkbox for every left click this frame that landed within its rect.
+    /// Returns true if it was toggled.
+    pub fn update(&mut self, window: &Window) -> bool {
+        let clicks = window
+            .events()
+            .iter()
+            .filter(|event| match event {
+                Event::Mouse(mouse) => {
+                    mouse.kind == MouseEventKind::Down(MouseButton::Left)
+                        && self.rect.contains(vec2(mouse.column, mouse.row))
+                }
+                _ => false,
+            })
+            .count();
+
+        for _ in 0..clicks {
+            self.toggle();
+        }
+        clicks > 0
+    }
+}
+
+impl Render for Checkbox {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let mark = if self.checked { "[x]" } else { "[ ]" };
+        render!(buffer, loc => [
+            StyledContent::new(self.box_style, mark),
+            " ",
+            StyledContent::new(self.label_style, self.label.as_str()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(checkbox: &Checkbox) -> String {
+        let mut buffer = Buffer::new((12, 1));
+        render!(buffer, vec2(0, 0) => [ checkbox ]);
+        buffer.to_plain_string()
+    }
+
+    #[test]
+    fn space_toggles_the_mark() {
+        let mut checkbox = Checkbox::new("Sound", Rect::new((0, 0), (9, 1)));
+        assert_eq!(rendered(&checkbox), "[ ] Sound");
+
+        assert!(checkbox.handle_key(KeyEvent::from(KeyCode::Char(' '))));
+        assert_eq!(rendered(&checkbox), "[x] Sound");
+
+        assert!(checkbox.handle_key(KeyEvent::from(KeyCode::Enter)));
+        assert_eq!(rendered(&checkbox), "[ ] Sound");
+    }
+
+    #[test]
+    fn other_keys_do_nothing() {
+        let mut checkbox = Checkbox::new("Sound", Rect::new((0, 0), (9, 1)));
+
+        assert!(!checkbox.handle_key(KeyEvent::from(KeyCode::Char('x'))));
+        assert!(!checkbox.checked);
+    }
+}
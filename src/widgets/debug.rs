@@ -0,0 +1,210 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use crate::prelude::*;
+
+/// Collects debug messages and draws the most recent ones in a corner of the screen, over the rest of the frame.
+/// Only the background of the covered area changes, so whatever is beneath stays visible around the messages.
+/// Unlike printing, it never disturbs the window. Pressing the toggle key while calling `update` shows or hides it.
+/**
+`Example`
+```rust, no_run
+use std::time::Duration;
+
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+let mut overlay = DebugOverlay::new(5);
+
+loop {
+    window.update(Duration::from_millis(16))?;
+    overlay.update(window.events());
+
+    overlay.log(format!("{} events", window.events().len()));
+
+    overlay.render(&mut window);
+}
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugOverlay {
+    lines: VecDeque<String>,
+    capacity: usize,
+    pub visible: bool,
+    pub toggle_key: KeyCode,
+    pub corner: Alignment,
+    pub style: ContentStyle,
+    pub background: Color,
+}
+
+impl DebugOverlay {
+    /// Creates a visible overlay that keeps the given number of most recent messages.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+            visible: true,
+            toggle_key: KeyCode::F(12),
+            corner: Alignment::new(Align::End, Align::End),
+            style: ContentStyle::new().white(),
+            background: Color::DarkGrey,
+        }
+    }
+
+    pub fn with_toggle_key(mut self, key: KeyCode) -> Self {
+        self.toggle_key = key;
+        self
+    }
+
+    pub fn with_corner(mut self, corner: Alignment) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    pub fn with_style(mut self, style: ContentStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Styles the messages with the theme's primary style, over the color of its borders.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.primary;
+        if let Some(color) = theme.border.foreground_color {
+            self.background = color;
+        }
+        self
+    }
+
+    /// Adds a message, dropping the oldest one once more than the capacity are stored.
+    /// Messages spanning multiple lines are split, with each line counting towards the capacity.
+    pub fn log(&mut self, message: impl Display) {
+        for line in message.to_string().lines() {
+            self.lines.push_back(line.to_string());
+        }
+        while self.lines.len() > self.capacity {
+            self.lines.pop_front();
+        }
+    }
+
+    /// The stored messages, from oldest to newest.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Shows or hides the overlay if the toggle key was pressed in the events, usually `Window::events`.
+    pub fn update(&mut self, events: &[Event]) {
+        let pressed = events.iter().any(|event| {
+            matches!(event, Event::Key(key) if key.code == self.toggle_key && key.kind == KeyEventKind::Press)
+        });
+        if pressed {
+            self.visible = !self.visible;
+        }
+    }
+
+    /// Draws the stored messages in the overlay's corner, if it is visible.
+    pub fn render(&self, mut buffer: impl AsMut<Buffer>) {
+        if !self.visible || self.lines.is_empty() {
+            return;
+        }
+        let buffer = buffer.as_mut();
+        let screen = buffer.size();
+
+        let width = self
+            .lines
+            .iter()
+            .map(|line| text_width(line))
+            .max()
+            .unwrap_or(0)
+            .min(screen.x);
+        let height = (self.lines.len() as u16).min(screen.y);
+        let loc = vec2(
+            self.corner.horizontal.offset(screen.x, width),
+            self.corner.vertical.offset(screen.y, height),
+        );
+
+        let background = self.background;
+        buffer.map_style(Rect::new(loc, (width, height)), |style| {
+            style.background_color = Some(background)
+        });
+
+        let mut style = self.style;
+        style.background_color = Some(background);
+
+        // Keep the newest lines when there isn't room for all of them.
+        let skip = self.lines.len() - height as usize;
+        for (y, line) in self.lines.iter().skip(skip).enumerate() {
+            let row = vec2(loc.x, loc.y + y as u16);
+            // Measured the same way as the width, so wide characters line up with it.
+            let end = buffer.set_str(row, line);
+            buffer.map_style(Rect::new(row, (end.x - row.x, 1)), |s| *s = style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(overlay: &DebugOverlay, size: impl Into<Vec2>) -> Vec<String> {
+        let mut buffer = Buffer::new(size);
+        overlay.render(&mut buffer);
+        buffer.to_lines()
+    }
+
+    #[test]
+    fn only_the_newest_lines_are_kept() {
+        let mut overlay =
+            DebugOverlay::new(2).with_corner(Alignment::new(Align::Start, Align::Start));
+        overlay.log("one");
+        overlay.log("two\nthree");
+
+        assert_eq!(overlay.lines().collect::<Vec<_>>(), ["two", "three"]);
+        assert_eq!(rendered(&overlay, (6, 3)), ["two", "three", ""]);
+    }
+
+    #[test]
+    fn toggle_key_shows_and_hides() {
+        let mut overlay = DebugOverlay::new(2);
+        overlay.log("hi");
+
+        overlay.update(&[Event::Key(KeyCode::Char('a').into())]);
+        assert!(overlay.visible);
+
+        overlay.update(&[Event::Key(KeyCode::F(12).into())]);
+        assert!(!overlay.visible);
+        assert_eq!(rendered(&overlay, (4, 2)), ["", ""]);
+
+        overlay.update(&[Event::Key(KeyCode::F(12).into())]);
+        assert!(overlay.visible);
+        assert_eq!(rendered(&overlay, (4, 2)), ["", "  hi"]);
+    }
+
+    #[test]
+    fn wide_characters_fill_the_measured_width() {
+        let mut overlay = DebugOverlay::new(2);
+        overlay.log("日本");
+        overlay.log("abcd");
+
+        let mut buffer = Buffer::new((6, 2));
+        overlay.render(&mut buffer);
+
+        assert_eq!(buffer.get((2, 0)).text(), "日");
+        assert_eq!(buffer.get((4, 0)).text(), "本");
+        assert_eq!(buffer.to_lines()[1], "  abcd");
+        // The whole width is covered, and nothing to the left of it.
+        for x in 0..6 {
+            let background = buffer.get((x, 0)).style().background_color;
+            assert_eq!(background.is_some(), x >= 2, "{x}");
+        }
+    }
+}
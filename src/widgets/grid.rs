@@ -0,0 +1,199 @@
+use crate::prelude::*;
+
+/// A fixed-size board of cells, each taking up the same number of columns and rows.
+/// Cells are laid out with the given spacing between them, and can optionally be separated by grid lines,
+/// which are drawn in the first column and row of each gap.
+/// A good base for chess, minesweeper, and other board games.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let mut board = Grid::new((3, 3), (1, 1)).with_spacing((1, 0));
+board.set((1, 1), 'X');
+board.set((0, 2), 'O');
+
+let mut buffer = Buffer::new(board.size());
+render!(buffer, vec2(0, 0) => [ board ]);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T: Render> {
+    cells: Vec<Option<T>>,
+    dimensions: Vec2,
+    pub cell_size: Vec2,
+    pub spacing: Vec2,
+    pub lines: Option<ContentStyle>,
+}
+
+impl<T: Render> Grid<T> {
+    /// Creates an empty grid with the given number of columns and rows, where every cell is `cell_size` large.
+    pub fn new(dimensions: impl Into<Vec2>, cell_size: impl Into<Vec2>) -> Self {
+        let dimensions = dimensions.into();
+        Self {
+            cells: (0..dimensions.x as usize * dimensions.y as usize)
+                .map(|_| None)
+                .collect(),
+            dimensions,
+            cell_size: cell_size.into(),
+            spacing: vec2(0, 0),
+            lines: None,
+        }
+    }
+
+    pub fn with_spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Separates the cells with grid lines in the given style.
+    /// Any axis without spacing is given a single column or row to hold the lines.
+    pub fn with_lines(mut self, style: ContentStyle) -> Self {
+        self.lines = Some(style);
+        self
+    }
+
+    /// The number of columns and rows in the grid.
+    pub fn dimensions(&self) -> Vec2 {
+        self.dimensions
+    }
+
+    /// The size the grid takes up when rendered.
+    pub fn size(&self) -> Vec2 {
+        let gap = self.gap();
+        let span = |count: u16, cell: u16, gap: u16| {
+            count
+                .saturating_mul(cell)
+                .saturating_add(count.saturating_sub(1).saturating_mul(gap))
+        };
+        vec2(
+            span(self.dimensions.x, self.cell_size.x, gap.x),
+            span(self.dimensions.y, self.cell_size.y, gap.y),
+        )
+    }
+
+    /// Returns the location, relative to the grid, that the given cell is rendered at.
+    pub fn cell_offset(&self, cell: impl Into<Vec2>) -> Vec2 {
+        let cell = cell.into();
+        let gap = self.gap();
+        vec2(
+            cell.x
+                .saturating_mul(self.cell_size.x.saturating_add(gap.x)),
+            cell.y
+                .saturating_mul(self.cell_size.y.saturating_add(gap.y)),
+        )
+    }
+
+    pub fn get(&self, cell: impl Into<Vec2>) -> Option<&T> {
+        self.index_of(cell.into())
+            .and_then(|i| self.cells[i].as_ref())
+    }
+
+    pub fn get_mut(&mut self, cell: impl Into<Vec2>) -> Option<&mut T> {
+        self.index_of(cell.into())
+            .and_then(|i| self.cells[i].as_mut())
+    }
+
+    /// Places the value in the cell. Cells outside of the grid are ignored.
+    pub fn set(&mut self, cell: impl Into<Vec2>, value: T) {
+        if let Some(i) = self.index_of(cell.into()) {
+            self.cells[i] = Some(value);
+        }
+    }
+
+    /// Empties the cell, returning whatever was in it.
+    pub fn take(&mut self, cell: impl Into<Vec2>) -> Option<T> {
+        self.index_of(cell.into())
+            .and_then(|i| self.cells[i].take())
+    }
+
+    fn index_of(&self, cell: Vec2) -> Option<usize> {
+        if cell.x >= self.dimensions.x || cell.y >= self.dimensions.y {
+            return None;
+        }
+        Some(cell.y as usize * self.dimensions.x as usize + cell.x as usize)
+    }
+
+    fn gap(&self) -> Vec2 {
+        match self.lines {
+            Some(_) => vec2(self.spacing.x.max(1), self.spacing.y.max(1)),
+            None => self.spacing,
+        }
+    }
+}
+
+impl<T: Render> Render for Grid<T> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let size = self.size();
+        let gap = self.gap();
+        let step = vec2(
+            self.cell_size.x.saturating_add(gap.x),
+            self.cell_size.y.saturating_add(gap.y),
+        );
+
+        if let Some(style) = self.lines {
+            let end_x = loc.x.saturating_add(size.x).min(buffer.size().x);
+            let end_y = loc.y.saturating_add(size.y).min(buffer.size().y);
+
+            for y in loc.y..end_y {
+                for x in loc.x..end_x {
+                    let on_column = (x - loc.x) % step.x == self.cell_size.x;
+                    let on_row = (y - loc.y) % step.y == self.cell_size.y;
+                    let glyph = match (on_column, on_row) {
+                        (true, true) => '┼',
+                        (true, false) => '│',
+                        (false, true) => '─',
+                        (false, false) => continue,
+                    };
+                    buffer.set((x, y), StyledContent::new(style, glyph));
+                }
+            }
+        }
+
+        for y in 0..self.dimensions.y {
+            for x in 0..self.dimensions.x {
+                if let Some(value) = self.get((x, y)) {
+                    let offset = self.cell_offset((x, y));
+                    value.render(
+                        vec2(
+                            loc.x.saturating_add(offset.x),
+                            loc.y.saturating_add(offset.y),
+                        ),
+                        buffer,
+                    );
+                }
+            }
+        }
+
+        vec2(loc.x.saturating_add(size.x), loc.y.saturating_add(size.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_land_at_their_offset_with_spacing() {
+        let mut grid = Grid::new((3, 3), (1, 1)).with_spacing((2, 1));
+        for y in 0..3 {
+            for x in 0..3 {
+                grid.set((x, y), char::from(b'a' + (y * 3 + x) as u8));
+            }
+        }
+        assert_eq!(grid.size(), vec2(7, 5));
+
+        let mut buffer = Buffer::new(grid.size());
+        render!(buffer, vec2(0, 0) => [ grid ]);
+
+        assert_eq!(buffer.to_lines(), ["a  b  c", "", "d  e  f", "", "g  h  i"]);
+    }
+
+    #[test]
+    fn huge_grids_saturate_instead_of_overflowing() {
+        let grid = Grid::<char>::new((300, 1), (300, 1));
+
+        assert_eq!(grid.size(), vec2(u16::MAX, 1));
+        assert_eq!(grid.cell_offset((299, 0)), vec2(u16::MAX, 0));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+/// Wraps an element, rendering it in one style normally, and another while the mouse hovers its rect.
+/// Call `update` each frame so the hover state follows the window's mouse position.
+/// The element is always drawn at its rect, whatever location it is rendered at,
+/// so the cells that are styled are exactly the ones that count as hovering it.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let rect = Rect::new((0, 0), (6, 1));
+let mut button = Hoverable::new(
+    "Button",
+    rect,
+    ContentStyle::new().white(),
+    ContentStyle::new().black().on_white(),
+);
+
+button.update(window.mouse_pos());
+render!(window, rect.loc => [ button ]);
+# Ok(())
+# }
+```
+*/
+pub struct Hoverable<R: Render> {
+    pub inner: R,
+    pub rect: Rect,
+    pub style: ContentStyle,
+    pub hover_style: ContentStyle,
+    hovered: bool,
+}
+
+impl<R: Render> Hoverable<R> {
+    pub fn new(inner: R, rect: Rect, style: ContentStyle, hover_style: ContentStyle) -> Self {
+        Self {
+            inner,
+            rect,
+            style,
+            hover_style,
+            hovered: false,
+        }
+    }
+
+    /// Updates the hover state from the mouse position, usually `Window::mouse_pos`.
+    pub fn update(&mut self, mouse_pos: Vec2) {
+        self.hovered = self.rect.contains(mouse_pos);
+    }
+
+    /// Returns true if the mouse was over the rect at the last update.
+    pub fn hovered(&self) -> bool {
+        self.hovered
+    }
+}
+
+impl<R: Render> Render for Hoverable<R> {
+    fn render(&self, _: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let end = self.inner.render(self.rect.loc, buffer);
+
+        let style = if self.hovered {
+            self.hover_style
+        } else {
+            self.style
+        };
+        buffer.map_style(self.rect, |s| *s = style);
+
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button() -> Hoverable<&'static str> {
+        Hoverable::new(
+            "Button",
+            Rect::new((2, 1), (6, 1)),
+            ContentStyle::new().white(),
+            ContentStyle::new().black().on_white(),
+        )
+    }
+
+    #[test]
+    fn hovers_only_inside_the_rect() {
+        let mut button = button();
+        for (pos, hovered) in [
+            (vec2(2, 1), true),
+            (vec2(7, 1), true),
+            (vec2(1, 1), false),
+            (vec2(8, 1), false),
+            (vec2(4, 0), false),
+            (vec2(4, 2), false),
+        ] {
+            button.update(pos);
+            assert_eq!(button.hovered(), hovered, "{pos:?}");
+        }
+    }
+
+    #[test]
+    fn styles_the_rect_it_hovers() {
+        let mut button = button();
+        button.update(vec2(3, 1));
+
+        let mut buffer = Buffer::new((10, 3));
+        // Drawn at its rect, even when rendered somewhere else.
+        render!(buffer, vec2(0, 0) => [ button ]);
+
+        assert_eq!(buffer.to_lines()[1], "  Button");
+        for (loc, cell) in buffer.iter() {
+            let expected = if button.rect.contains(loc) {
+                button.hover_style
+            } else {
+                ContentStyle::default()
+            };
+            assert_eq!(*cell.style(), expected, "{loc:?}");
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// A single row built from differently styled spans, laid out one after another.
+/// Newlines are dropped from anything pushed, so a Line never spills onto another row.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let mut line = Line::new();
+line.push("Score: ");
+line.push_styled("120".yellow().bold());
+line.push(" points");
+
+let mut buffer = Buffer::new((line.width(), 1));
+render!(buffer, vec2(0, 0) => [ line ]);
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct Line {
+    pub spans: Vec<StyledContent<String>>,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a span of unstyled text to the end of the line.
+    pub fn push(&mut self, text: impl Into<String>) {
+        self.push_styled(StyledContent::new(ContentStyle::default(), text.into()));
+    }
+
+    /// Adds a styled span to the end of the line.
+    pub fn push_styled<D: Display>(&mut self, content: StyledContent<D>) {
+        let text = content.content().to_string().replace(['\n', '\r'], "");
+        self.spans.push(StyledContent::new(*content.style(), text));
+    }
+
+    /// The number of cells the line takes up when rendered, one for each character.
+    pub fn width(&self) -> u16 {
+        let chars: usize = self
+            .spans
+            .iter()
+            .map(|span| span.content().chars().count())
+            .sum();
+        chars.min(u16::MAX as usize) as u16
+    }
+
+    /// The text of the line without any styling.
+    pub fn plain(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.content().as_str())
+            .collect()
+    }
+}
+
+impl From<&str> for Line {
+    fn from(value: &str) -> Self {
+        let mut line = Self::new();
+        line.push(value);
+        line
+    }
+}
+
+impl From<String> for Line {
+    fn from(value: String) -> Self {
+        let mut line = Self::new();
+        line.push(value);
+        line
+    }
+}
+
+impl<D: Display> From<StyledContent<D>> for Line {
+    fn from(value: StyledContent<D>) -> Self {
+        let mut line = Self::new();
+        line.push_styled(value);
+        line
+    }
+}
+
+impl<D: Display> FromIterator<StyledContent<D>> for Line {
+    fn from_iter<I: IntoIterator<Item = StyledContent<D>>>(iter: I) -> Self {
+        let mut line = Self::new();
+        for span in iter {
+            line.push_styled(span);
+        }
+        line
+    }
+}
+
+impl Render for Line {
+    fn render(&self, mut loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        for span in &self.spans {
+            loc = span.render(loc, buffer);
+        }
+        loc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lays_out_styled_spans_one_after_another() {
+        let mut line = Line::new();
+        line.push_styled("ab".red());
+        line.push("cd");
+        line.push_styled("e\nf".blue());
+        assert_eq!(line.width(), 6);
+
+        let mut buffer = Buffer::new((8, 2));
+        let end = render!(buffer, vec2(0, 0) => [ line ]);
+
+        assert_eq!(end, vec2(6, 0));
+        assert_eq!(buffer.to_lines(), ["abcdef", ""]);
+        assert_eq!(
+            buffer.get((1, 0)).style().foreground_color,
+            Some(Color::Red)
+        );
+        assert_eq!(buffer.get((2, 0)).style().foreground_color, None);
+        assert_eq!(
+            buffer.get((5, 0)).style().foreground_color,
+            Some(Color::Blue)
+        );
+    }
+
+    #[test]
+    fn width_matches_the_cells_rendered() {
+        let line = Line::from("日本");
+
+        let mut buffer = Buffer::new((4, 1));
+        let end = render!(buffer, vec2(0, 0) => [ line ]);
+
+        assert_eq!(line.width(), end.x);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+/// Scrolling text that shows a `width` wide window into its text, starting `offset` characters in.
+/// The text wraps around with the separator between repeats, and `tick` advances it by one character.
+/// Text that already fits within the width is shown as is, without scrolling.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let mut marquee = Marquee::new("A very long status message", 10);
+let mut buffer = Buffer::new((10, 1));
+
+render!(buffer, vec2(0, 0) => [ marquee ]);
+marquee.tick();
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Marquee {
+    pub text: String,
+    pub width: u16,
+    pub offset: usize,
+    pub separator: String,
+    pub style: ContentStyle,
+}
+
+impl Marquee {
+    pub fn new(text: impl Into<String>, width: u16) -> Self {
+        Self {
+            text: text.into(),
+            width,
+            offset: 0,
+            separator: " ".repeat(3),
+            style: ContentStyle::default(),
+        }
+    }
+
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    pub fn with_style(mut self, style: ContentStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns true if the text is too long to fit, and so scrolls.
+    pub fn scrolls(&self) -> bool {
+        self.text.chars().count() > self.width as usize
+    }
+
+    /// Advances the text by one character, wrapping back around once the whole loop has passed.
+    pub fn tick(&mut self) {
+        if !self.scrolls() {
+            return;
+        }
+        let len = self.text.chars().count() + self.separator.chars().count();
+        self.offset = (self.offset + 1) % len;
+    }
+
+    /// Returns the text currently visible through the marquee.
+    pub fn visible(&self) -> String {
+        if !self.scrolls() {
+            return self.text.clone();
+        }
+
+        let looped: Vec<char> = self.text.chars().chain(self.separator.chars()).collect();
+        looped
+            .iter()
+            .cycle()
+            .skip(self.offset % looped.len())
+            .take(self.width as usize)
+            .collect()
+    }
+}
+
+impl Render for Marquee {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        render!(buffer, loc => [ StyledContent::new(self.style, self.visible()) ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticking_scrolls_the_visible_text() {
+        let mut marquee = Marquee::new("abcdef", 4).with_separator("|");
+        assert_eq!(marquee.visible(), "abcd");
+
+        marquee.tick();
+        assert_eq!(marquee.visible(), "bcde");
+
+        for _ in 0..4 {
+            marquee.tick();
+        }
+        assert_eq!(marquee.visible(), "f|ab");
+
+        marquee.tick();
+        marquee.tick();
+        assert_eq!(marquee.visible(), "abcd");
+    }
+
+    #[test]
+    fn short_text_stays_still() {
+        let mut marquee = Marquee::new("hi", 4);
+        marquee.tick();
+
+        assert!(!marquee.scrolls());
+        assert_eq!(marquee.visible(), "hi");
+    }
+}
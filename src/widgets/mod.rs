@@ -0,0 +1,13 @@
+pub mod checkbox;
+pub mod debug;
+pub mod grid;
+pub mod hoverable;
+pub mod line;
+pub mod marquee;
+pub mod offset;
+pub mod radio;
+pub mod rule;
+pub mod sprite;
+pub mod tabs;
+pub mod text;
+pub mod tree;
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+/// Renders the inner element shifted right and down by `delta` from wherever it is placed.
+/// Useful for nudging a whole group of elements without recomputing each of their locations.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let mut buffer = Buffer::new((20, 5));
+
+// Starts at (2, 1)
+render!(buffer, vec2(0, 0) => [ Offset::new("Indented", (2, 1)) ]);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Offset<R: Render> {
+    pub inner: R,
+    pub delta: Vec2,
+}
+
+impl<R: Render> Offset<R> {
+    pub fn new(inner: R, delta: impl Into<Vec2>) -> Self {
+        Self {
+            inner,
+            delta: delta.into(),
+        }
+    }
+}
+
+impl<R: Render> Render for Offset<R> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let loc = vec2(
+            loc.x.saturating_add(self.delta.x),
+            loc.y.saturating_add(self.delta.y),
+        );
+        self.inner.render(loc, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_inner_element_shifted() {
+        let mut buffer = Buffer::new((6, 3));
+        let end = render!(buffer, vec2(1, 0) => [ Offset::new("hi", (2, 1)) ]);
+
+        assert_eq!(end, vec2(5, 1));
+        assert_eq!(buffer.to_lines(), ["", "   hi", ""]);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::prelude::*;
+
+/// A set of mutually exclusive options, one per row, rendered as `(•) selected` and `( ) other`.
+/// Each option pairs a label with the value it stands for, and the Up and Down keys move the selection through `handle_key`.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let mut difficulty = RadioGroup::new([("Easy", 1), ("Normal", 2), ("Hard", 3)]);
+
+for event in window.events() {
+    if let Event::Key(key) = event {
+        difficulty.handle_key(*key);
+    }
+}
+
+render!(window, vec2(0, 0) => [ difficulty ]);
+let speed = difficulty.value();
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadioGroup<T> {
+    pub options: Vec<(String, T)>,
+    pub selected: usize,
+    pub mark_style: ContentStyle,
+    pub label_style: ContentStyle,
+    pub selected_style: ContentStyle,
+}
+
+impl<T> RadioGroup<T> {
+    pub fn new<S: Into<String>>(options: impl IntoIterator<Item = (S, T)>) -> Self {
+        Self {
+            options: options
+                .into_iter()
+                .map(|(label, value)| (label.into(), value))
+                .collect(),
+            selected: 0,
+            mark_style: ContentStyle::default(),
+            label_style: ContentStyle::default(),
+            selected_style: ContentStyle::default(),
+        }
+    }
+
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn with_mark_style(mut self, style: ContentStyle) -> Self {
+        self.mark_style = style;
+        self
+    }
+
+    pub fn with_label_style(mut self, style: ContentStyle) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// The style of the selected option's label.
+    pub fn with_selected_style(mut self, style: ContentStyle) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Styles the marks with the theme's accent, the labels with its primary style,
+    /// and the selected option with its selected style.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.mark_style = theme.accent;
+        self.label_style = theme.primary;
+        self.selected_style = theme.selected;
+        self
+    }
+
+    /// Selects the next option, wrapping around to the first.
+    pub fn next(&mut self) {
+        if !self.options.is_empty() {
+            self.selected = (self.selected + 1) % self.options.len();
+        }
+    }
+
+    /// Selects the previous option, wrapping around to the last.
+    pub fn previous(&mut self) {
+        if !self.options.is_empty() {
+            self.selected = (self.selected + self.options.len() - 1) % self.options.len();
+        }
+    }
+
+    /// Moves the selection on Up or Down, returning true if the key was used.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.code {
+            KeyCode::Up => self.previous(),
+            KeyCode::Down => self.next(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the value of the selected option.
+    pub fn value(&self) -> Option<&T> {
+        self.options.get(self.selected).map(|(_, value)| value)
+    }
+}
+
+impl<T> Render for RadioGroup<T> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let mut end = loc;
+        for (i, (label, _)) in self.options.iter().enumerate() {
+            let (mark, style) = if i == self.selected {
+                ("(•)", self.selected_style)
+            } else {
+                ("( )", self.label_style)
+            };
+            end = render!(buffer, vec2(loc.x, loc.y + i as u16) => [
+                StyledContent::new(self.mark_style, mark),
+                " ",
+                StyledContent::new(style, label.as_str()),
+            ]);
+        }
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(radio: &RadioGroup<u8>) -> Vec<usize> {
+        let mut buffer = Buffer::new((12, 3));
+        render!(buffer, vec2(0, 0) => [ radio ]);
+        buffer
+            .to_lines()
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("(•)"))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn arrow_keys_move_the_single_mark() {
+        let mut radio = RadioGroup::new([("Easy", 1), ("Normal", 2), ("Hard", 3)]);
+        assert_eq!(marked(&radio), [0]);
+
+        assert!(radio.handle_key(KeyEvent::from(KeyCode::Down)));
+        assert!(radio.handle_key(KeyEvent::from(KeyCode::Down)));
+        assert_eq!(marked(&radio), [2]);
+        assert_eq!(radio.value(), Some(&3));
+
+        assert!(radio.handle_key(KeyEvent::from(KeyCode::Up)));
+        assert_eq!(marked(&radio), [1]);
+        assert_eq!(radio.value(), Some(&2));
+    }
+
+    #[test]
+    fn selection_wraps_around() {
+        let mut radio = RadioGroup::new([("Easy", 1), ("Hard", 3)]);
+
+        radio.handle_key(KeyEvent::from(KeyCode::Up));
+        assert_eq!(radio.value(), Some(&3));
+        assert!(!radio.handle_key(KeyEvent::from(KeyCode::Left)));
+    }
+}
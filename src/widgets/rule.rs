@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+/// A horizontal line with an optional label centered along it, like `──── Section ────`.
+/// Without a set width, the rule runs to the right edge of whatever it is rendered into,
+/// so `render_in` makes it span the rect.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let mut buffer = Buffer::new((20, 1));
+
+render!(buffer, vec2(0, 0) => [ Rule::new().with_label("Section") ]);
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub label: Option<String>,
+    pub style: ContentStyle,
+    pub glyph: char,
+    pub width: Option<u16>,
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self {
+            label: None,
+            style: ContentStyle::default(),
+            glyph: '─',
+            width: None,
+        }
+    }
+}
+
+impl Rule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_style(mut self, style: ContentStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Styles the rule with the theme's border style.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.border;
+        self
+    }
+
+    pub fn with_glyph(mut self, glyph: char) -> Self {
+        self.glyph = glyph;
+        self
+    }
+
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+impl Render for Rule {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let available = buffer.size().x.saturating_sub(loc.x);
+        let width = self.width.map_or(available, |w| w.min(available));
+
+        let mut line: Vec<char> = vec![self.glyph; width as usize];
+
+        if let Some(label) = &self.label {
+            let label: Vec<char> = format!(" {label} ").chars().take(width as usize).collect();
+            let start = (line.len() - label.len()) / 2;
+            line.splice(start..start + label.len(), label);
+        }
+
+        for (i, chr) in line.into_iter().enumerate() {
+            buffer.set(
+                vec2(loc.x + i as u16, loc.y),
+                StyledContent::new(self.style, chr),
+            );
+        }
+
+        vec2(loc.x + width, loc.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_is_centered_along_the_rule() {
+        let mut buffer = Buffer::new((11, 1));
+        render!(buffer, vec2(0, 0) => [ Rule::new().with_label("ab") ]);
+
+        assert_eq!(buffer.to_lines(), ["─── ab ────"]);
+    }
+
+    #[test]
+    fn unlabeled_rule_is_a_full_line_of_glyphs() {
+        let mut buffer = Buffer::new((6, 1));
+        let end = render!(buffer, vec2(1, 0) => [ Rule::new().with_glyph('=') ]);
+
+        assert_eq!(end, vec2(6, 0));
+        assert_eq!(buffer.to_lines(), [" ====="]);
+    }
+
+    #[test]
+    fn label_is_clipped_to_the_width() {
+        let mut buffer = Buffer::new((10, 1));
+        render!(buffer, vec2(0, 0) => [ Rule::new().with_label("Section").with_width(5) ]);
+
+        assert_eq!(buffer.to_lines(), [" Sect"]);
+    }
+}
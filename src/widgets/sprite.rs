@@ -0,0 +1,104 @@
+use crate::prelude::*;
+
+/// A pre-rendered element with an anchor point, which is the cell that lands on the location it is rendered at.
+/// With a centered anchor, a sprite's location is its middle rather than its top left corner.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let ship = Sprite::new("/^\\").centered();
+let loc = vec2(window.size().x / 2, window.size().y - 2);
+
+render!(window, loc => [ ship ]);
+let hitbox = ship.bounds(loc);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sprite {
+    pub buffer: Buffer,
+    pub anchor: Vec2,
+}
+
+impl Sprite {
+    /// Renders the element into the smallest buffer that holds it, anchored at its top left corner.
+    pub fn new(element: impl Render) -> Self {
+        Self::from_buffer(Buffer::sized_element(element))
+    }
+
+    pub fn from_buffer(buffer: Buffer) -> Self {
+        Self {
+            buffer,
+            anchor: vec2(0, 0),
+        }
+    }
+
+    pub fn with_anchor(mut self, anchor: impl Into<Vec2>) -> Self {
+        self.anchor = anchor.into();
+        self
+    }
+
+    /// Moves the anchor to the middle of the sprite.
+    pub fn centered(mut self) -> Self {
+        let size = self.buffer.size();
+        self.anchor = vec2(size.x / 2, size.y / 2);
+        self
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.buffer.size()
+    }
+
+    /// Returns the top left corner of the sprite when rendered at the location.
+    pub fn origin(&self, loc: Vec2) -> Vec2 {
+        vec2(
+            loc.x.saturating_sub(self.anchor.x),
+            loc.y.saturating_sub(self.anchor.y),
+        )
+    }
+
+    /// Returns the rect the sprite covers when rendered at the location.
+    pub fn bounds(&self, loc: impl Into<Vec2>) -> Rect {
+        Rect::new(self.origin(loc.into()), self.buffer.size())
+    }
+}
+
+impl Render for Sprite {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let origin = self.origin(loc);
+        self.buffer.render(origin, buffer);
+
+        let size = self.buffer.size();
+        vec2(origin.x + size.x, origin.y + size.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centered_sprite_renders_offset_by_half_its_size() {
+        let sprite = Sprite::new(Lines(vec!["abc", "def", "ghi"])).centered();
+        assert_eq!(sprite.anchor, vec2(1, 1));
+
+        let mut buffer = Buffer::new((6, 5));
+        let end = render!(buffer, vec2(3, 2) => [ sprite ]);
+
+        assert_eq!(end, vec2(5, 4));
+        assert_eq!(buffer.to_lines(), ["", "  abc", "  def", "  ghi", ""]);
+        assert_eq!(sprite.bounds((3, 2)), Rect::new((2, 1), (3, 3)));
+    }
+
+    #[test]
+    fn bounds_stop_at_the_screen_origin() {
+        let sprite = Sprite::new("abc").with_anchor((2, 0));
+
+        assert_eq!(sprite.bounds((1, 0)), Rect::new((0, 0), (3, 1)));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::prelude::*;
+
+/// A single line of tab titles separated by a divider, with the selected tab drawn in the active style.
+/// Without a set width, the bar runs to the right edge of whatever it is rendered into.
+/// When the titles don't fit, the bar scrolls just far enough to keep the selected tab in view.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let mut tabs = Tabs::new(["Files", "Search", "Settings"]);
+tabs.next();
+
+let rects = tabs.render_tabs(vec2(0, 0), window.buffer_mut());
+for (i, rect) in rects.iter().enumerate() {
+    if rect.contains(window.mouse_pos()) {
+        tabs.selected = i;
+    }
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tabs {
+    pub titles: Vec<String>,
+    pub selected: usize,
+    pub divider: String,
+    pub style: ContentStyle,
+    pub active_style: ContentStyle,
+    pub width: Option<u16>,
+}
+
+impl Tabs {
+    pub fn new<S: Into<String>>(titles: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            titles: titles.into_iter().map(Into::into).collect(),
+            selected: 0,
+            divider: " │ ".to_string(),
+            style: ContentStyle::default(),
+            active_style: ContentStyle::new().reverse(),
+            width: None,
+        }
+    }
+
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn with_divider(mut self, divider: impl Into<String>) -> Self {
+        self.divider = divider.into();
+        self
+    }
+
+    pub fn with_style(mut self, style: ContentStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_active_style(mut self, style: ContentStyle) -> Self {
+        self.active_style = style;
+        self
+    }
+
+    /// Styles the inactive tabs with the theme's muted style, and the active tab with its selected style.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.muted;
+        self.active_style = theme.selected;
+        self
+    }
+
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Selects the next tab, wrapping around to the first.
+    pub fn next(&mut self) {
+        if !self.titles.is_empty() {
+            self.selected = (self.selected + 1) % self.titles.len();
+        }
+    }
+
+    /// Selects the previous tab, wrapping around to the last.
+    pub fn previous(&mut self) {
+        if !self.titles.is_empty() {
+            self.selected = (self.selected + self.titles.len() - 1) % self.titles.len();
+        }
+    }
+
+    /// Returns the title of the selected tab.
+    pub fn selected_title(&self) -> Option<&str> {
+        self.titles.get(self.selected).map(String::as_str)
+    }
+
+    /// Renders the bar, returning the on-screen rect of each tab, in the same order as the titles.
+    /// Tabs that are scrolled out of view get a rect with no width.
+    pub fn render_tabs(&self, loc: Vec2, buffer: &mut Buffer) -> Vec<Rect> {
+        let available = buffer.size().x.saturating_sub(loc.x);
+        let width = self.width.map_or(available, |w| w.min(available)) as usize;
+
+        // Lay the whole bar out as one line, remembering where each tab sits along it.
+        let divider_len = self.divider.chars().count();
+        let mut spans = vec![];
+        let mut start = 0;
+        for title in &self.titles {
+            let len = title.chars().count();
+            spans.push((start, start + len));
+            start += len + divider_len;
+        }
+
+        let scroll = spans
+            .get(self.selected)
+            .map_or(0, |(_, end)| end.saturating_sub(width));
+
+        let mut line = vec![];
+        for (i, title) in self.titles.iter().enumerate() {
+            if i > 0 {
+                line.extend(self.divider.chars().map(|c| (c, self.style)));
+            }
+            let style = if i == self.selected {
+                self.active_style
+            } else {
+                self.style
+            };
+            line.extend(title.chars().map(|c| (c, style)));
+        }
+
+        for (i, (chr, style)) in line.into_iter().skip(scroll).take(width).enumerate() {
+            buffer.set(
+                vec2(loc.x + i as u16, loc.y),
+                StyledContent::new(style, chr),
+            );
+        }
+
+        spans
+            .into_iter()
+            .map(|(start, end)| {
+                let start = start.clamp(scroll, scroll + width) - scroll;
+                let end = end.clamp(scroll, scroll + width) - scroll;
+                Rect::new((loc.x + start as u16, loc.y), ((end - start) as u16, 1))
+            })
+            .collect()
+    }
+}
+
+impl Render for Tabs {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let end = self
+            .render_tabs(loc, buffer)
+            .iter()
+            .map(|rect| rect.loc.x + rect.size.x)
+            .max()
+            .unwrap_or(loc.x);
+        vec2(end, loc.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selected_tab_uses_the_active_style() {
+        let tabs = Tabs::new(["One", "Two", "Three"])
+            .with_divider("|")
+            .with_selected(1)
+            .with_active_style(ContentStyle::new().red());
+
+        let mut buffer = Buffer::new((20, 1));
+        let rects = tabs.render_tabs(vec2(1, 0), &mut buffer);
+
+        assert_eq!(buffer.to_lines(), [" One|Two|Three"]);
+        assert_eq!(
+            rects,
+            [
+                Rect::new((1, 0), (3, 1)),
+                Rect::new((5, 0), (3, 1)),
+                Rect::new((9, 0), (5, 1)),
+            ]
+        );
+        assert_eq!(
+            buffer.get((5, 0)).style().foreground_color,
+            Some(Color::Red)
+        );
+        assert_eq!(buffer.get((1, 0)).style().foreground_color, None);
+    }
+
+    #[test]
+    fn scrolls_to_keep_the_selected_tab_in_view() {
+        let tabs = Tabs::new(["One", "Two", "Three"])
+            .with_divider("|")
+            .with_selected(2)
+            .with_width(6);
+
+        let mut buffer = Buffer::new((20, 1));
+        let rects = tabs.render_tabs(vec2(0, 0), &mut buffer);
+
+        assert_eq!(buffer.to_lines(), ["|Three"]);
+        assert_eq!(rects[0].size.x, 0);
+        assert_eq!(rects[2], Rect::new((1, 0), (5, 1)));
+    }
+
+    #[test]
+    fn next_and_previous_wrap_around() {
+        let mut tabs = Tabs::new(["a", "b", "c"]);
+
+        tabs.previous();
+        assert_eq!(tabs.selected_title(), Some("c"));
+        tabs.next();
+        assert_eq!(tabs.selected_title(), Some("a"));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::prelude::*;
+
+/// A block of styled lines stacked top to bottom, each aligned within the width of the block.
+/// With a wrap width, lines longer than it are broken at spaces where possible, keeping each span's style.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+let text = Text::new([
+    Line::from("Game Over".red().bold()),
+    Line::from("Press any key to continue"),
+])
+.with_align(Align::Center)
+.with_wrap(12);
+
+let mut buffer = Buffer::new(text.size());
+render!(buffer, vec2(0, 0) => [ text ]);
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct Text {
+    pub lines: Vec<Line>,
+    pub align: Align,
+    pub wrap: Option<u16>,
+}
+
+impl Text {
+    pub fn new(lines: impl IntoIterator<Item = Line>) -> Self {
+        Self {
+            lines: lines.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_wrap(mut self, width: u16) -> Self {
+        self.wrap = Some(width);
+        self
+    }
+
+    /// Returns the lines as they are laid out, after wrapping.
+    pub fn wrapped(&self) -> Vec<Line> {
+        match self.wrap {
+            Some(width) => self
+                .lines
+                .iter()
+                .flat_map(|line| wrap_line(line, width))
+                .collect(),
+            None => self.lines.clone(),
+        }
+    }
+
+    /// The size of the block, as wide as its widest line and as tall as its line count after wrapping.
+    pub fn size(&self) -> Vec2 {
+        let lines = self.wrapped();
+        vec2(
+            lines.iter().map(Line::width).max().unwrap_or(0),
+            lines.len() as u16,
+        )
+    }
+}
+
+impl Render for Text {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let lines = self.wrapped();
+        let width = lines.iter().map(Line::width).max().unwrap_or(0);
+
+        let mut end = loc;
+        for (y, line) in lines.iter().enumerate() {
+            let x = loc.x + self.align.offset(width, line.width());
+            end = line.render(vec2(x, loc.y + y as u16), buffer);
+        }
+        end
+    }
+}
+
+/// Breaks the line into rows no wider than the width, at the last space that fits where there is one.
+fn wrap_line(line: &Line, width: u16) -> Vec<Line> {
+    let width = width.max(1);
+    let chars: Vec<(char, ContentStyle)> = line
+        .spans
+        .iter()
+        .flat_map(|span| span.content().chars().map(|c| (c, *span.style())))
+        .collect();
+
+    let mut rows = vec![];
+    let mut start = 0;
+    while start < chars.len() {
+        // Wrapped rows never start with the spaces they were broken at.
+        if !rows.is_empty() {
+            while start < chars.len() && chars[start].0 == ' ' {
+                start += 1;
+            }
+            if start == chars.len() {
+                break;
+            }
+        }
+
+        // Find how many characters fit on this row.
+        let mut end = start;
+        let mut used = 0;
+        // Every character is drawn into a single cell, so that is how much of the row it takes up.
+        while end < chars.len() && used < width {
+            used += 1;
+            end += 1;
+        }
+
+        let mut next = end;
+        if end < chars.len() {
+            // Break at the first space after a word, so a row is never left empty.
+            if let Some(space) =
+                (start + 1..end).rfind(|&i| chars[i].0 == ' ' && chars[i - 1].0 != ' ')
+            {
+                end = space;
+                next = end + 1;
+            } else if chars[end].0 == ' ' {
+                next = end + 1;
+            }
+        }
+
+        rows.push(to_line(&chars[start..end]));
+        start = next;
+    }
+
+    if rows.is_empty() {
+        rows.push(Line::new());
+    }
+    rows
+}
+
+/// Groups runs of characters that share a style back into spans.
+fn to_line(chars: &[(char, ContentStyle)]) -> Line {
+    let mut runs: Vec<(ContentStyle, String)> = vec![];
+    for (chr, style) in chars {
+        match runs.last_mut() {
+            Some((run_style, text)) if run_style == style => text.push(*chr),
+            _ => runs.push((*style, chr.to_string())),
+        }
+    }
+    runs.into_iter()
+        .map(|(style, text)| StyledContent::new(style, text))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain_rows(text: &Text) -> Vec<String> {
+        text.wrapped().iter().map(Line::plain).collect()
+    }
+
+    #[test]
+    fn centers_each_line_in_the_block() {
+        let text = Text::new([Line::from("abcd"), Line::from("ab")]).with_align(Align::Center);
+        assert_eq!(text.size(), vec2(4, 2));
+
+        let mut buffer = Buffer::new(text.size());
+        render!(buffer, vec2(0, 0) => [ text ]);
+
+        assert_eq!(buffer.to_lines(), ["abcd", " ab"]);
+    }
+
+    #[test]
+    fn wrapping_keeps_span_styles() {
+        let line: Line = ["hello ".red(), "big world".blue()].into_iter().collect();
+        let text = Text::new([line]).with_wrap(6);
+
+        assert_eq!(plain_rows(&text), ["hello", "big", "world"]);
+
+        let mut buffer = Buffer::new(text.size());
+        render!(buffer, vec2(0, 0) => [ text ]);
+
+        assert_eq!(
+            buffer.get(vec2(0, 0)).style().foreground_color,
+            Some(Color::Red)
+        );
+        assert_eq!(
+            buffer.get(vec2(0, 2)).style().foreground_color,
+            Some(Color::Blue)
+        );
+    }
+
+    #[test]
+    fn wrapped_rows_skip_leading_spaces() {
+        let text = Text::new([Line::from("aaa  bbbbbbb cc")]).with_wrap(4);
+        assert_eq!(plain_rows(&text), ["aaa", "bbbb", "bbb", "cc"]);
+    }
+}
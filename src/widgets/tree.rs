@@ -0,0 +1,313 @@
+use crate::prelude::*;
+
+/// A single node of a Tree, holding a label, a value, and any children nested beneath it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    pub label: String,
+    pub value: T,
+    pub children: Vec<TreeNode<T>>,
+    pub expanded: bool,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a collapsed node with no children.
+    pub fn new(label: impl Into<String>, value: T) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            children: vec![],
+            expanded: false,
+        }
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+}
+
+/// A row of a Tree as it appears on screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeRow<'a, T> {
+    /// The indices leading from the roots down to the node.
+    pub path: Vec<usize>,
+    pub depth: usize,
+    pub node: &'a TreeNode<T>,
+}
+
+/// A collapsible tree of nodes, like a file explorer, rendered as indented rows with `▸` and `▾` markers.
+/// Only the children of expanded nodes are shown, and selection moves through the shown rows.
+/// With a set height, the rows scroll to keep the selection in view.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let mut window = Window::init()?;
+
+let mut tree = Tree::new([
+    TreeNode::new("src", "src/").with_children([
+        TreeNode::new("main.rs", "src/main.rs"),
+        TreeNode::new("lib.rs", "src/lib.rs"),
+    ]),
+    TreeNode::new("Cargo.toml", "Cargo.toml"),
+]);
+
+for event in window.events() {
+    if let Event::Key(key) = event {
+        match key.code {
+            KeyCode::Up => tree.previous(),
+            KeyCode::Down => tree.next(),
+            KeyCode::Enter => tree.toggle(),
+            _ => {}
+        }
+    }
+}
+
+render!(window, vec2(0, 0) => [ tree ]);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tree<T> {
+    pub roots: Vec<TreeNode<T>>,
+    selected: Vec<usize>,
+    pub height: Option<u16>,
+    pub style: ContentStyle,
+    pub selected_style: ContentStyle,
+}
+
+impl<T> Tree<T> {
+    pub fn new(roots: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self {
+            roots: roots.into_iter().collect(),
+            selected: vec![0],
+            height: None,
+            style: ContentStyle::default(),
+            selected_style: ContentStyle::new().reverse(),
+        }
+    }
+
+    pub fn with_height(mut self, height: u16) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn with_style(mut self, style: ContentStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_selected_style(mut self, style: ContentStyle) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Styles the rows with the theme's primary style, and the selected row with its selected style.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.style = theme.primary;
+        self.selected_style = theme.selected;
+        self
+    }
+
+    /// Returns every row that is currently shown, in order from top to bottom.
+    pub fn visible(&self) -> Vec<TreeRow<'_, T>> {
+        fn walk<'a, T>(
+            nodes: &'a [TreeNode<T>],
+            path: &mut Vec<usize>,
+            rows: &mut Vec<TreeRow<'a, T>>,
+        ) {
+            for (i, node) in nodes.iter().enumerate() {
+                path.push(i);
+                rows.push(TreeRow {
+                    path: path.clone(),
+                    depth: path.len() - 1,
+                    node,
+                });
+                if node.expanded {
+                    walk(&node.children, path, rows);
+                }
+                path.pop();
+            }
+        }
+
+        let mut rows = vec![];
+        walk(&self.roots, &mut vec![], &mut rows);
+        rows
+    }
+
+    /// The path of indices from the roots to the selected node.
+    pub fn selected_path(&self) -> &[usize] {
+        &self.selected
+    }
+
+    /// Selects the node at the path, if there is one.
+    /// Any collapsed nodes along the way are expanded so that it is shown.
+    pub fn select(&mut self, path: &[usize]) {
+        if self.node(path).is_none() {
+            return;
+        }
+        for depth in 1..path.len() {
+            if let Some(node) = self.node_mut(&path[..depth]) {
+                node.expanded = true;
+            }
+        }
+        self.selected = path.to_vec();
+    }
+
+    /// Returns the selected node.
+    pub fn selected(&self) -> Option<&TreeNode<T>> {
+        self.node(&self.selected)
+    }
+
+    /// Returns the node at the path of indices from the roots.
+    pub fn node(&self, path: &[usize]) -> Option<&TreeNode<T>> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.roots.get(*first)?, |node, i| node.children.get(*i))
+    }
+
+    pub fn node_mut(&mut self, path: &[usize]) -> Option<&mut TreeNode<T>> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.roots.get_mut(*first)?, |node, i| {
+                node.children.get_mut(*i)
+            })
+    }
+
+    /// Moves the selection to the next shown row.
+    pub fn next(&mut self) {
+        let rows = self.visible();
+        let next = match self.selected_row(&rows) {
+            Some(i) => rows.get(i + 1),
+            None => rows.first(),
+        };
+        if let Some(row) = next {
+            self.selected = row.path.clone();
+        }
+    }
+
+    /// Moves the selection to the previous shown row.
+    pub fn previous(&mut self) {
+        let rows = self.visible();
+        let previous = match self.selected_row(&rows) {
+            Some(i) => i.checked_sub(1).and_then(|i| rows.get(i)),
+            None => rows.first(),
+        };
+        if let Some(row) = previous {
+            self.selected = row.path.clone();
+        }
+    }
+
+    /// Expands the selected node if it is collapsed, or collapses it if it is expanded.
+    pub fn toggle(&mut self) {
+        if let Some(node) = self.node_mut(&self.selected.clone()) {
+            node.expanded = !node.expanded;
+        }
+    }
+
+    pub fn expand(&mut self) {
+        if let Some(node) = self.node_mut(&self.selected.clone()) {
+            node.expanded = true;
+        }
+    }
+
+    pub fn collapse(&mut self) {
+        if let Some(node) = self.node_mut(&self.selected.clone()) {
+            node.expanded = false;
+        }
+    }
+
+    fn selected_row(&self, rows: &[TreeRow<'_, T>]) -> Option<usize> {
+        rows.iter().position(|row| row.path == self.selected)
+    }
+}
+
+impl<T> Render for Tree<T> {
+    fn render(&self, loc: Vec2, buffer: &mut Buffer) -> Vec2 {
+        let rows = self.visible();
+        let height = self.height.map_or(rows.len(), |h| h as usize);
+
+        // Scroll just far enough to keep the selected row in view.
+        let scroll = self
+            .selected_row(&rows)
+            .map_or(0, |i| (i + 1).saturating_sub(height));
+
+        let mut end = loc;
+        for (y, row) in rows.iter().skip(scroll).take(height).enumerate() {
+            let marker = match (row.node.children.is_empty(), row.node.expanded) {
+                (true, _) => ' ',
+                (false, true) => '▾',
+                (false, false) => '▸',
+            };
+            let style = if row.path == self.selected {
+                self.selected_style
+            } else {
+                self.style
+            };
+            let text = format!("{}{marker} {}", "  ".repeat(row.depth), row.node.label);
+            end = render!(buffer, vec2(loc.x, loc.y + y as u16) => [ StyledContent::new(style, text) ]);
+        }
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Tree<u8> {
+        Tree::new([
+            TreeNode::new("src", 0)
+                .with_children([TreeNode::new("main.rs", 1), TreeNode::new("lib.rs", 2)]),
+            TreeNode::new("Cargo.toml", 3),
+        ])
+    }
+
+    fn labels(tree: &Tree<u8>) -> Vec<&str> {
+        tree.visible()
+            .iter()
+            .map(|row| row.node.label.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn expanding_shows_children_and_collapsing_hides_them() {
+        let mut tree = tree();
+        assert_eq!(labels(&tree), ["src", "Cargo.toml"]);
+
+        tree.expand();
+        assert_eq!(labels(&tree), ["src", "main.rs", "lib.rs", "Cargo.toml"]);
+
+        let mut buffer = Buffer::new((14, 4));
+        render!(buffer, vec2(0, 0) => [ tree ]);
+        assert_eq!(
+            buffer.to_lines(),
+            ["▾ src", "    main.rs", "    lib.rs", "  Cargo.toml"]
+        );
+
+        tree.collapse();
+        assert_eq!(labels(&tree), ["src", "Cargo.toml"]);
+    }
+
+    #[test]
+    fn selection_skips_collapsed_children() {
+        let mut tree = tree();
+
+        tree.next();
+        assert_eq!(tree.selected().map(|node| node.value), Some(3));
+
+        tree.previous();
+        tree.toggle();
+        tree.next();
+        assert_eq!(tree.selected_path(), [0, 0]);
+        assert_eq!(tree.selected().map(|node| node.value), Some(1));
+    }
+}
@@ -1,6 +1,10 @@
 use std::{
+    fmt,
+    fs::OpenOptions,
     io::{self, Stdout, Write},
-    panic::{set_hook, take_hook},
+    panic::{set_hook, take_hook, PanicHookInfo},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU8, Ordering},
     time::Duration,
 };
 
@@ -10,14 +14,138 @@ use crossterm::{
     execute, queue,
     terminal::{self, *},
     tty::IsTty,
+    Command,
 };
 
 pub use crate::prelude::*;
 
+/// The writer behind a window, forwarding everything to the terminal and to the recorder if one is active.
+struct Output {
+    out: Box<dyn Write + Send>,
+    recorder: Option<Recorder>,
+}
+
+impl Output {
+    fn new(out: impl Write + Send + 'static) -> Self {
+        Self {
+            out: Box::new(out),
+            recorder: None,
+        }
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.out.write(buf)?;
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&buf[..written]);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()?;
+        if let Some(recorder) = &mut self.recorder {
+            recorder.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Where a window reads its events from, so that they can be faked without a terminal.
+trait EventSource {
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool>;
+    fn read(&mut self) -> io::Result<Event>;
+}
+
+/// Reads events from the terminal.
+struct TerminalEvents;
+
+impl EventSource for TerminalEvents {
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool> {
+        event::poll(timeout)
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        event::read()
+    }
+}
+
+/// Controls which terminal features `Window::init_opts` sets up, and so which ones `restore` undoes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowOptions {
+    /// Render to the alternate screen, leaving the normal terminal untouched once restored.
+    /// Without it the final frame stays visible after the app exits.
+    pub alternate_screen: bool,
+    /// Capture mouse events.
+    pub mouse: bool,
+    /// Report focus gained and lost events.
+    pub focus: bool,
+    /// Leave line wrapping on while the window is active.
+    pub line_wrap: bool,
+}
+
+impl Default for WindowOptions {
+    fn default() -> Self {
+        Self {
+            alternate_screen: true,
+            mouse: true,
+            focus: true,
+            line_wrap: false,
+        }
+    }
+}
+
+// Terminal features a window can enable, tracked so they can be undone on restore or panic.
+const ALTERNATE_SCREEN: u8 = 1;
+const MOUSE: u8 = 1 << 1;
+const FOCUS: u8 = 1 << 2;
+const NO_LINE_WRAP: u8 = 1 << 3;
+const KEYBOARD: u8 = 1 << 4;
+const CURSOR_COLOR: u8 = 1 << 5;
+
+/// The features enabled by the active window, for the panic hook to undo.
+static ACTIVE_FEATURES: AtomicU8 = AtomicU8::new(0);
+
+/// A command that sets the color of the terminal cursor using the OSC 12 escape sequence.
+/// `Color::Reset` goes back to the terminal's own cursor color. Terminals without support ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetCursorColor(pub Color);
+
+impl Command for SetCursorColor {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        match to_rgb(self.0) {
+            Some((r, g, b)) => write!(f, "\x1b]12;rgb:{r:02x}/{g:02x}/{b:02x}\x07"),
+            None => ResetCursorColor.write_ansi(f),
+        }
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A command that puts the terminal cursor back to its default color, using the OSC 112 escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResetCursorColor;
+
+impl Command for ResetCursorColor {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "\x1b]112\x07")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 #[derive(Default)]
 pub struct Inline {
     active: bool,
     kitty: bool,
+    cooked: bool,
     start: u16,
 }
 
@@ -34,32 +162,59 @@ impl AsMut<Buffer> for Window {
 ```rust, no_run
 use ascii_forge::prelude::*;
 
+# fn main() -> std::io::Result<()> {
 let mut window = Window::init()?;
 
 render!(
     window,
-    [
-        (10, 10) => "Element Here!"
-    ]
-)
+    vec2(10, 10) => [ "Element Here!" ]
+);
+# Ok(())
+# }
 ```
 */
 pub struct Window {
-    io: io::Stdout,
+    io: Output,
+    input: Box<dyn EventSource + Send>,
     buffers: [Buffer; 2],
     active_buffer: usize,
+    clear_cell: Cell,
     events: Vec<Event>,
 
     // Input Helpers,
     mouse_pos: Vec2,
 
+    // Cursor
+    cursor: Vec2,
+    cursor_visible: bool,
+    cursor_style: Option<SetCursorStyle>,
+    // Where the terminal cursor was last placed, None if something has moved it since
+    emitted_cursor: Option<Vec2>,
+    emitted_cursor_visible: bool,
+
     // Inlining
     inline: Option<Inline>,
 
+    // The terminal features enabled by init, and undone by restore
+    options: WindowOptions,
+
     // Event Handling
     just_resized: bool,
+    max_events_per_frame: usize,
+
+    // Whether keyboard enhancement flags were pushed and need popping on restore
+    keyboard_enhanced: bool,
+
+    // Whether the cursor color was changed and needs resetting on restore
+    cursor_colored: bool,
+
+    // Whether frames are wrapped in synchronized updates
+    synchronized_output: bool,
 }
 
+/// The number of events read each frame before the rest are left for the next one.
+const DEFAULT_MAX_EVENTS_PER_FRAME: usize = 1024;
+
 impl Default for Window {
     fn default() -> Self {
         Self::init().expect("Init should have succeeded")
@@ -70,65 +225,93 @@ impl Window {
     /// Creates a new window from the given stdout.
     /// Please prefer to use init as it will do all of the terminal init stuff.
     pub fn new(io: io::Stdout) -> io::Result<Self> {
-        Ok(Self {
-            io,
-            buffers: [Buffer::new(size()?), Buffer::new(size()?)],
-            active_buffer: 0,
-            events: vec![],
-
-            mouse_pos: vec2(0, 0),
-
-            inline: None,
-
-            just_resized: false,
-        })
+        Ok(Self::from_output(Output::new(io), size()?.into(), None))
     }
 
     /// Creates a new window built for inline using the given Stdout and height.
     pub fn new_inline(io: io::Stdout, height: u16) -> io::Result<Self> {
         let size = vec2(size()?.0, height);
-        Ok(Self {
+        Ok(Self::from_output(
+            Output::new(io),
+            size,
+            Some(Inline::default()),
+        ))
+    }
+
+    /// Builds a window of the given size that writes to the output, without touching the terminal.
+    fn from_output(io: Output, size: Vec2, inline: Option<Inline>) -> Self {
+        Self {
             io,
+            input: Box::new(TerminalEvents),
             buffers: [Buffer::new(size), Buffer::new(size)],
             active_buffer: 0,
+            clear_cell: Cell::default(),
             events: vec![],
 
             mouse_pos: vec2(0, 0),
 
-            inline: Some(Inline::default()),
+            cursor: vec2(0, 0),
+            cursor_visible: false,
+            cursor_style: None,
+            emitted_cursor: None,
+            emitted_cursor_visible: false,
+
+            inline,
+
+            options: WindowOptions::default(),
 
             just_resized: false,
-        })
+            max_events_per_frame: DEFAULT_MAX_EVENTS_PER_FRAME,
+
+            keyboard_enhanced: false,
+
+            cursor_colored: false,
+
+            synchronized_output: synchronized_output_detected(),
+        }
     }
 
     /// Initializes a window that is prepared for inline rendering.
     /// Height is the number of columns that your terminal will need.
     pub fn init_inline(height: u16) -> io::Result<Self> {
-        let stdout = io::stdout();
-
-        assert!(stdout.is_tty());
+        let stdout = tty_stdout()?;
 
         Window::new_inline(stdout, height)
     }
 
+    /// Initializes an inline window that leaves the terminal in its normal "cooked" mode, without raw mode.
+    /// Ctrl-C, line editing, and typed input keep working as usual, making it suited to status lines and progress bars.
+    /// The tradeoff is that the window receives no events, not even resizes,
+    /// so `update` simply waits out the poll duration and `update_idle` is unsupported.
+    pub fn init_inline_cooked(height: u16) -> io::Result<Self> {
+        let mut window = Window::init_inline(height)?;
+        if let Some(inline) = &mut window.inline {
+            inline.cooked = true;
+        }
+        Ok(window)
+    }
+
+    /// Returns true if the window was made with `init_inline_cooked`, and so never enters raw mode.
+    fn cooked(&self) -> bool {
+        self.inline.as_ref().is_some_and(|inline| inline.cooked)
+    }
+
     /// Initializes the window, and returns a new Window for your use.
     pub fn init() -> io::Result<Self> {
-        enable_raw_mode()?;
-
-        let mut stdout = io::stdout();
+        Window::init_opts(WindowOptions::default())
+    }
 
-        assert!(stdout.is_tty());
+    /// Initializes the window using the given options to decide which terminal features are set up.
+    pub fn init_opts(options: WindowOptions) -> io::Result<Self> {
+        let mut stdout = tty_stdout()?;
 
-        execute!(
-            stdout,
-            EnterAlternateScreen,
-            EnableMouseCapture,
-            EnableFocusChange,
-            Hide,
-            DisableLineWrap,
-        )?;
+        enable_raw_mode()?;
+        setup(&mut stdout, options)?;
 
-        Window::new(stdout)
+        let mut window = Window::new(stdout)?;
+        window.options = options;
+        window.publish_features();
+        Ok(window)
     }
 
     /// Enables the kitty keyboard protocol
@@ -144,9 +327,11 @@ impl Window {
                 inline.kitty = true;
             } else {
                 execute!(
-                    self.io(),
+                    self.io,
                     PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::all())
                 )?;
+                self.keyboard_enhanced = true;
+                self.publish_features();
             }
             Ok(())
         } else {
@@ -157,6 +342,18 @@ impl Window {
         }
     }
 
+    /// Sets whether each frame is wrapped in a synchronized update, which stops large updates from tearing.
+    /// Enabled by default on terminals known to support it, others will ignore the sequences.
+    pub fn set_synchronized_output(&mut self, enabled: bool) {
+        self.synchronized_output = enabled;
+    }
+
+    /// Sets how many events are read from the terminal each frame, leaving the rest for the following frames.
+    /// Stops a flood of input, like fast mouse movement, from holding up a frame. Defaults to 1024.
+    pub fn set_max_events_per_frame(&mut self, max: usize) {
+        self.max_events_per_frame = max.max(1);
+    }
+
     /// Returns the active Buffer, as a reference.
     pub fn buffer(&self) -> &Buffer {
         &self.buffers[self.active_buffer]
@@ -167,10 +364,56 @@ impl Window {
         &mut self.buffers[self.active_buffer]
     }
 
+    /// Runs the closure with a Frame of the active buffer and this frame's input, returning its result.
+    pub fn draw<R>(&mut self, f: impl FnOnce(&mut Frame) -> R) -> R {
+        let mut frame = Frame::new(
+            &mut self.buffers[self.active_buffer],
+            &self.events,
+            self.mouse_pos,
+        );
+        f(&mut frame)
+    }
+
+    /// Darkens every color in the active buffer towards black by the given amount, from 0 to 1.
+    /// Gives a quick greyed out background to draw a modal over. See `Buffer::dim`.
+    pub fn dim(&mut self, amount: f32) {
+        self.buffer_mut().dim(amount);
+    }
+
     /// Swaps the buffers, clearing the old buffer. Used automatically by the window's update method.
     pub fn swap_buffers(&mut self) {
         self.active_buffer = 1 - self.active_buffer;
-        self.buffers[self.active_buffer].clear();
+        self.buffers[self.active_buffer].fill(self.clear_cell.clone());
+    }
+
+    /// Forces the next render to redraw every cell, rather than only the ones that changed.
+    /// Use this when the terminal's contents can't be trusted, like after something else wrote to it.
+    pub fn mark_dirty(&mut self) {
+        self.just_resized = true;
+    }
+
+    /// Sets the cell that buffers are cleared to when swapped, so a colored background persists between frames.
+    pub fn set_clear_cell(&mut self, cell: impl Into<Cell>) {
+        self.clear_cell = cell.into();
+    }
+
+    /// Sets the background color of the whole window.
+    /// Buffers are cleared to this color when swapped, and the next render redraws every cell with it.
+    pub fn set_background(&mut self, color: Color) {
+        let old = std::mem::replace(&mut self.clear_cell, Cell::bg(color));
+        let new = self.clear_cell.clone();
+
+        for (_, cell) in self.buffer_mut().iter_mut() {
+            if *cell == old {
+                *cell = new.clone();
+            }
+        }
+        self.just_resized = true;
+    }
+
+    /// Returns the cell that buffers are cleared to when swapped.
+    pub fn clear_cell(&self) -> &Cell {
+        &self.clear_cell
     }
 
     /// Returns the current known size of the buffer's window.
@@ -178,167 +421,463 @@ impl Window {
         self.buffer().size()
     }
 
+    /// Returns true if the window is at least as large as the given size in both directions.
+    pub fn require_min_size(&self, min: impl Into<Vec2>) -> bool {
+        let min = min.into();
+        let size = self.size();
+        size.x >= min.x && size.y >= min.y
+    }
+
+    /// If the window is smaller than the given size, clears it and centers a message saying how large it needs to be.
+    /// Call at the top of the loop, skipping the rest of the frame while `require_min_size` is false.
+    pub fn render_too_small_message(&mut self, min: impl Into<Vec2>) {
+        let min = min.into();
+        if self.require_min_size(min) {
+            return;
+        }
+
+        let clear = self.clear_cell.clone();
+        let size = self.size();
+        let buffer = self.buffer_mut();
+        buffer.fill(clear);
+
+        let message = format!("Terminal too small (need {}x{})", min.x, min.y);
+        message.render_in(
+            Rect::new((0, 0), size),
+            Alignment::new(Align::Center, Align::Center),
+            buffer,
+        );
+    }
+
     /// Restores the window to it's previous state from before the window's init method.
     /// If the window is inline, restore the inline render
     pub fn restore(&mut self) -> io::Result<()> {
-        if terminal::supports_keyboard_enhancement().is_ok() {
-            queue!(self.io, PopKeyboardEnhancementFlags)?;
-        }
-        if let Some(inline) = &self.inline {
-            execute!(
-                self.io,
-                DisableMouseCapture,
-                DisableFocusChange,
-                PopKeyboardEnhancementFlags,
-                Show,
-            )?;
+        let features = self.features();
+        self.keyboard_enhanced = false;
+        self.cursor_colored = false;
+        ACTIVE_FEATURES.store(0, Ordering::Relaxed);
 
-            if terminal::size()?.1 != inline.start + 1 {
-                print!(
-                    "{}",
-                    "\n".repeat(self.buffers[self.active_buffer].size().y as usize)
-                );
+        // Leave the cursor on the row below an inline window, so later output doesn't overwrite it.
+        if let Some(inline) = &self.inline {
+            if inline.active {
+                queue!(self.io, cursor::MoveTo(0, inline.start))?;
             }
+        }
 
-            disable_raw_mode()?;
+        teardown(&mut self.io, features)?;
+        self.io.recorder = None;
 
-            Ok(())
-        } else {
-            execute!(
-                self.io,
-                PopKeyboardEnhancementFlags,
-                LeaveAlternateScreen,
-                DisableMouseCapture,
-                DisableFocusChange,
-                Show,
-                EnableLineWrap,
-            )?;
+        disable_raw_mode()
+    }
 
-            disable_raw_mode()
+    /// Returns the terminal features this window currently has enabled.
+    fn features(&self) -> u8 {
+        let mut features = 0;
+        if self.keyboard_enhanced {
+            features |= KEYBOARD;
+        }
+        if self.cursor_colored {
+            features |= CURSOR_COLOR;
         }
+
+        match &self.inline {
+            Some(inline) if inline.active && inline.cooked => features |= NO_LINE_WRAP,
+            Some(inline) if inline.active => features |= MOUSE | FOCUS | NO_LINE_WRAP,
+            Some(_) => {}
+            None => {
+                if self.options.alternate_screen {
+                    features |= ALTERNATE_SCREEN;
+                }
+                if self.options.mouse {
+                    features |= MOUSE;
+                }
+                if self.options.focus {
+                    features |= FOCUS;
+                }
+                if !self.options.line_wrap {
+                    features |= NO_LINE_WRAP;
+                }
+            }
+        }
+
+        features
+    }
+
+    /// Shares the enabled terminal features with the panic hook.
+    fn publish_features(&self) {
+        ACTIVE_FEATURES.store(self.features(), Ordering::Relaxed);
     }
 
     /// Renders the window to the screen. should really only be used by the update method, but if you need a custom system, you can use this.
     pub fn render(&mut self) -> io::Result<()> {
-        if self.inline.is_some() {
-            if !self.inline.as_ref().expect("Inline should be some").active {
-                // Make room for the inline render
-                print!("{}", "\n".repeat(self.buffer().size().y as usize));
+        if self.inline.as_ref().is_some_and(|inline| !inline.active) {
+            self.activate_inline()?;
+        }
 
-                enable_raw_mode()?;
+        let top = match &self.inline {
+            Some(inline) => inline.start.saturating_sub(self.size().y),
+            None => 0,
+        };
+
+        let full = std::mem::take(&mut self.just_resized);
+        if queue_diff(
+            &mut self.io,
+            &self.buffers[1 - self.active_buffer],
+            &self.buffers[self.active_buffer],
+            top,
+            full,
+        )? {
+            self.emitted_cursor = None;
+        }
 
-                execute!(
-                    self.io,
-                    EnableMouseCapture,
-                    EnableFocusChange,
-                    DisableLineWrap,
-                    Hide,
-                )?;
+        Ok(())
+    }
 
-                if self.inline.as_ref().expect("Inline should be some").kitty {
-                    execute!(
-                        self.io,
-                        PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::all())
-                    )?;
-                }
+    /// Renders only the cells inside the given rect, for apps that track their own dirty regions.
+    /// Anything drawn outside of the rect this frame is dropped, so that the next frame still diffs
+    /// against what is actually on screen. Falls back to a full render when a full redraw is pending.
+    pub fn render_region(&mut self, rect: Rect) -> io::Result<()> {
+        let top = match &self.inline {
+            Some(inline) if inline.active => inline.start.saturating_sub(self.size().y),
+            Some(_) => return self.render(),
+            None => 0,
+        };
+
+        if self.just_resized || self.buffers[0].size() != self.buffers[1].size() {
+            return self.render();
+        }
 
-                let inline = self.inline.as_mut().expect("Inline should be some");
+        let [first, second] = &mut self.buffers;
+        let (old, new) = if self.active_buffer == 0 {
+            (second, first)
+        } else {
+            (first, second)
+        };
 
-                inline.active = true;
-                inline.start = cursor::position()?.1;
-            }
+        for x in 0..new.size().x {
+            for y in 0..new.size().y {
+                if old.get((x, y)) == new.get((x, y)) {
+                    continue;
+                }
 
-            for (loc, cell) in
-                self.buffers[1 - self.active_buffer].diff(&self.buffers[self.active_buffer])
-            {
-                queue!(
-                    self.io,
-                    cursor::MoveTo(
-                        loc.x,
-                        self.inline.as_ref().expect("Inline should be some").start
-                            - self.buffers[self.active_buffer].size().y
-                            + loc.y
-                    ),
-                    Print(cell),
-                )?;
+                if rect.contains((x, y)) {
+                    queue!(self.io, cursor::MoveTo(x, top + y), Print(new.get((x, y))))?;
+                    self.emitted_cursor = None;
+                } else {
+                    new.set((x, y), old.get((x, y)).clone());
+                }
             }
+        }
+
+        Ok(())
+    }
+
+    /// Sets up the terminal for an inline window the first time it renders.
+    fn activate_inline(&mut self) -> io::Result<()> {
+        let start = self.reserve_inline_rows()?;
+        self.enter_inline(start)
+    }
 
-            queue!(
+    /// Sets up the terminal for the inline render, with the region ending just above the given row.
+    /// Cooked windows stay out of raw mode, and skip the features that only matter for reading events.
+    fn enter_inline(&mut self, start: u16) -> io::Result<()> {
+        if self.cooked() {
+            execute!(self.io, DisableLineWrap, Hide)?;
+        } else {
+            enable_raw_mode()?;
+            execute!(
                 self.io,
-                cursor::MoveTo(
-                    0,
-                    self.inline.as_ref().expect("Inline should be some").start
-                        - self.buffers[self.active_buffer].size().y
-                )
+                EnableMouseCapture,
+                EnableFocusChange,
+                DisableLineWrap,
+                Hide,
             )?;
-        } else {
-            if self.just_resized {
-                self.just_resized = false;
-                let cell = self.buffers[self.active_buffer].size();
-                for x in 0..cell.x {
-                    for y in 0..cell.y {
-                        let cell = self.buffers[self.active_buffer].get((x, y));
-                        queue!(self.io, cursor::MoveTo(x, y), Print(cell))?;
-                    }
-                }
-            }
-            for (loc, cell) in
-                self.buffers[1 - self.active_buffer].diff(&self.buffers[self.active_buffer])
-            {
-                queue!(self.io, cursor::MoveTo(loc.x, loc.y), Print(cell))?;
+        }
+        let Some(inline) = &mut self.inline else {
+            return Ok(());
+        };
+
+        if inline.kitty && !inline.cooked {
+            execute!(
+                self.io,
+                PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::all())
+            )?;
+            self.keyboard_enhanced = true;
+        }
+
+        inline.active = true;
+        inline.start = start;
+        self.emitted_cursor = None;
+        self.publish_features();
+
+        Ok(())
+    }
+
+    /// Returns the row the inline render is anchored to, the first row below the rendered region.
+    /// None if the window isn't inline, or hasn't rendered yet.
+    pub fn inline_start(&self) -> Option<u16> {
+        self.inline
+            .as_ref()
+            .filter(|inline| inline.active)
+            .map(|inline| inline.start)
+    }
+
+    /// Makes room for the inline render below the cursor, starting on a fresh line
+    /// if something (like a shell prompt) is already on the current one.
+    /// Returns the row just below the reserved space.
+    fn reserve_inline_rows(&mut self) -> io::Result<u16> {
+        let height = self.size().y;
+        reserve_rows(
+            &mut self.io,
+            cursor::position()?,
+            height,
+            terminal::size()?.1,
+        )
+    }
+
+    /// Re-anchors an inline window below the current cursor row, redrawing it there on the next render.
+    /// Use this after printing output around the window, so it is drawn below that output.
+    pub fn reanchor(&mut self) -> io::Result<()> {
+        if !self.inline.as_ref().is_some_and(|inline| inline.active) {
+            // The window reserves its space when it first renders anyway.
+            return Ok(());
+        }
+
+        let start = self.reserve_inline_rows()?;
+        if let Some(inline) = &mut self.inline {
+            inline.start = start;
+        }
+        self.just_resized = true;
+        Ok(())
+    }
+
+    /// Returns the logical cursor position, relative to the window's buffer.
+    pub fn cursor(&self) -> Vec2 {
+        self.cursor
+    }
+
+    /// Returns the terminal row and column the cursor occupies on screen.
+    /// This matches `cursor()` unless the window is inline, where the render is offset
+    /// to wherever the inline region starts.
+    pub fn screen_cursor(&self) -> Vec2 {
+        let mut loc = self.cursor;
+        if let Some(inline) = &self.inline {
+            loc.y += inline.start.saturating_sub(self.size().y);
+        }
+        loc
+    }
+
+    /// Moves the cursor to the given location, clamped to the window's bounds.
+    pub fn set_cursor(&mut self, loc: impl Into<Vec2>) {
+        let loc = loc.into();
+        let size = self.size();
+        self.cursor = vec2(
+            loc.x.min(size.x.saturating_sub(1)),
+            loc.y.min(size.y.saturating_sub(1)),
+        );
+    }
+
+    /// Moves the cursor to a location relative to the given rect, clamped to the window's bounds.
+    pub fn set_cursor_in(&mut self, rect: Rect, local: impl Into<Vec2>) {
+        let local = local.into();
+        self.set_cursor(vec2(
+            rect.loc.x.saturating_add(local.x),
+            rect.loc.y.saturating_add(local.y),
+        ));
+    }
+
+    /// Sets whether the terminal cursor should be shown.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.cursor_visible = visible;
+    }
+
+    /// Sets the shape of the terminal cursor, applied on the next render.
+    pub fn set_cursor_style(&mut self, style: SetCursorStyle) {
+        self.cursor_style = Some(style);
+    }
+
+    /// Sets the color of the terminal cursor, which is put back to the terminal's default on restore.
+    /// See `SetCursorColor` for how it is sent.
+    pub fn set_cursor_color(&mut self, color: Color) -> io::Result<()> {
+        execute!(self.io, SetCursorColor(color))?;
+        self.cursor_colored = color != Color::Reset;
+        self.publish_features();
+        Ok(())
+    }
+
+    /// Draws a cursor into the active buffer by layering the given style onto the cell under the cursor,
+    /// for a cursor that looks the same on every terminal. `ContentStyle::new().reverse()` gives a block cursor.
+    /// Call this after the frame has been drawn; the cell goes back to normal when the buffers are swapped.
+    pub fn draw_cursor_cell(&mut self, style: ContentStyle) {
+        let loc = self.cursor;
+        let size = self.size();
+        if loc.x >= size.x || loc.y >= size.y {
+            return;
+        }
+        let cell = self.buffer_mut().get_mut(loc).style_mut();
+
+        if style.foreground_color.is_some() {
+            cell.foreground_color = style.foreground_color;
+        }
+        if style.background_color.is_some() {
+            cell.background_color = style.background_color;
+        }
+        if style.underline_color.is_some() {
+            cell.underline_color = style.underline_color;
+        }
+        cell.attributes.extend(style.attributes);
+    }
+
+    /// Places the terminal cursor according to the window's cursor state.
+    /// The engine tracks what it last sent, so only changes are written to the terminal.
+    /// Used automatically by the update method.
+    pub fn render_cursor(&mut self) -> io::Result<()> {
+        if let Some(style) = self.cursor_style.take() {
+            queue!(self.io, style)?;
+        }
+
+        if self.cursor_visible != self.emitted_cursor_visible {
+            self.emitted_cursor_visible = self.cursor_visible;
+            if self.cursor_visible {
+                queue!(self.io, Show)?;
+            } else {
+                queue!(self.io, Hide)?;
             }
         }
+
+        if !self.cursor_visible {
+            return Ok(());
+        }
+
+        let loc = self.screen_cursor();
+        if self.emitted_cursor != Some(loc) {
+            self.emitted_cursor = Some(loc);
+            queue!(self.io, cursor::MoveTo(loc.x, loc.y))?;
+        }
+
         Ok(())
     }
 
     /// Handles events, and renders the screen.
     pub fn update(&mut self, poll: Duration) -> io::Result<()> {
-        let cursor_pos = cursor::position()?;
+        self.present()?;
+
+        // Poll For Events
+        self.handle_event(poll)
+    }
+
+    /// Renders the screen, then waits as long as it takes for the next event before returning.
+    /// Ideal for apps that only change on input, like editors, as nothing is redrawn while idle.
+    pub fn update_idle(&mut self) -> io::Result<()> {
+        if self.cooked() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Cooked inline windows don't receive events to wait for",
+            ));
+        }
+
+        self.present()?;
+
+        self.events = vec![];
+
+        // Block until something happens, then take everything that arrived with it
+        let event = self.input.read()?;
+        self.insert_event(event);
+        self.drain_events()
+    }
+
+    /// Renders the frame, swaps the buffers, and flushes it all to the terminal.
+    fn present(&mut self) -> io::Result<()> {
+        // Have the terminal show the whole frame at once, rather than painting it as it arrives
+        if self.synchronized_output {
+            queue!(self.io, BeginSynchronizedUpdate)?;
+        }
 
         // Render Window
         self.render()?;
 
         self.swap_buffers();
 
-        queue!(self.io, cursor::MoveTo(cursor_pos.0, cursor_pos.1))?;
+        self.render_cursor()?;
 
-        // Flush Render To Stdout
-        self.io.flush()?;
-
-        // Poll For Events
-        self.handle_event(poll)?;
+        if self.synchronized_output {
+            queue!(self.io, EndSynchronizedUpdate)?;
+        }
 
-        Ok(())
+        // Flush Render To Stdout
+        self.io.flush()
     }
 
     /// Handles events. Used automatically by the update method, so no need to use it unless update is being used.
     pub fn handle_event(&mut self, poll: Duration) -> io::Result<()> {
         self.events = vec![];
 
-        if event::poll(poll)? {
-            // Get all queued events
-            while event::poll(Duration::ZERO)? {
-                let event = event::read()?;
-
-                match event {
-                    Event::Resize(width, height) => {
-                        if self.inline.is_none() {
-                            self.buffers =
-                                [Buffer::new((width, height)), Buffer::new((width, height))];
-                            self.just_resized = true;
-                        }
-                    }
-                    Event::Mouse(MouseEvent { column, row, .. }) => {
-                        self.mouse_pos = vec2(column, row)
-                    }
-                    _ => {}
+        // Reading events would swallow the input meant for the terminal's own line handling
+        if self.cooked() {
+            std::thread::sleep(poll);
+            return Ok(());
+        }
+
+        if self.input.poll(poll)? {
+            self.drain_events()?;
+        }
+
+        Ok(())
+    }
+
+    /// Takes the events that are already queued, without waiting for more.
+    /// Stops after `max_events_per_frame`, counting the one that may have already been read.
+    fn drain_events(&mut self) -> io::Result<()> {
+        let mut read = self.events.len();
+        while read < self.max_events_per_frame && self.input.poll(Duration::ZERO)? {
+            let event = self.input.read()?;
+            self.insert_event(event);
+            read += 1;
+        }
+        Ok(())
+    }
+
+    /// Adds an event to this frame's events, handling it just as if it came from the terminal.
+    /// Useful for simulating input, such as in tests.
+    pub fn insert_event(&mut self, event: Event) {
+        match event {
+            Event::Resize(width, height) if self.inline.is_none() => {
+                // Keep whatever has already been rendered this frame,
+                // the next render will redraw the whole screen anyway.
+                for buffer in &mut self.buffers {
+                    buffer.resize((width, height));
                 }
+                self.just_resized = true;
+
+                // Pull the cursor back inside the window if it shrank past it.
+                self.set_cursor(self.cursor);
+            }
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Moved => {
+                self.mouse_pos = vec2(mouse.column, mouse.row);
 
-                self.events.push(event);
+                // Moves only matter for where the mouse ended up, so only the latest is kept.
+                // Clicks, drags, and scrolls are left in place.
+                self.events
+                    .retain(|e| !matches!(e, Event::Mouse(m) if m.kind == MouseEventKind::Moved));
             }
+            Event::Mouse(MouseEvent { column, row, .. }) => self.mouse_pos = vec2(column, row),
+            _ => {}
         }
 
-        Ok(())
+        self.events.push(event);
+    }
+
+    /// Returns the number of events received this frame.
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if any key or mouse event was received this frame.
+    /// Apps that only change on input can use this to skip drawing idle frames.
+    pub fn had_input(&self) -> bool {
+        self.events
+            .iter()
+            .any(|e| matches!(e, Event::Key(_) | Event::Mouse(_)))
     }
 
     pub fn mouse_pos(&self) -> Vec2 {
@@ -350,6 +889,26 @@ impl Window {
         &self.events
     }
 
+    /// Returns -1, 0, or 1 depending on which of the two keys was pressed this frame.
+    /// If both (or neither) were pressed, the axis cancels out to 0.
+    pub fn axis(&self, neg: KeyCode, pos: KeyCode) -> i8 {
+        let pressed = |code: KeyCode| event!(self, Event::Key(e) => e.code == code && e.kind != KeyEventKind::Release);
+
+        pressed(pos) as i8 - pressed(neg) as i8
+    }
+
+    /// Returns true if the key was pressed this frame with exactly the given modifiers held.
+    /// Note that terminals usually report uppercase letters with `KeyModifiers::SHIFT`.
+    pub fn key_pressed(&self, code: KeyCode, mods: KeyModifiers) -> bool {
+        event!(self, Event::Key(e) => e.code == code && e.modifiers == mods && e.kind != KeyEventKind::Release)
+    }
+
+    /// Returns true if the key was pressed this frame without any modifiers,
+    /// so that a binding for `q` doesn't also fire on `Ctrl-q`.
+    pub fn key_pressed_no_mods(&self, code: KeyCode) -> bool {
+        self.key_pressed(code, KeyModifiers::NONE)
+    }
+
     /// Returns true if the mouse cursor is hovering the given rect.
     pub fn hover<V: Into<Vec2>>(&self, loc: V, size: V) -> io::Result<bool> {
         let loc = loc.into();
@@ -360,9 +919,67 @@ impl Window {
         Ok(pos.x <= loc.x + size.x && pos.x >= loc.x && pos.y <= loc.y + size.y && pos.y >= loc.y)
     }
 
-    pub fn io(&mut self) -> &mut Stdout {
+    /// Returns the terminal output the window writes to.
+    /// Anything written through this is also captured by a recording started with `record_to`.
+    pub fn io(&mut self) -> &mut impl Write {
         &mut self.io
     }
+
+    /// Starts recording everything the window writes into an asciinema v2 `.cast` file at the given path.
+    /// The recording stops once the window is restored.
+    pub fn record_to(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.io.recorder = Some(Recorder::new(path, self.size())?);
+        Ok(())
+    }
+}
+
+/// Checks the environment for a terminal known to support synchronized output.
+/// Skips the keyboard query `Supports::detect` would make, as it isn't needed here.
+fn synchronized_output_detected() -> bool {
+    Supports::from_env(false, |name| std::env::var(name).ok()).synchronized_output()
+}
+
+/// Returns stdout, or an Unsupported error if it isn't a terminal (such as when piped or in CI).
+fn tty_stdout() -> io::Result<Stdout> {
+    require_tty(io::stdout())
+}
+
+/// Returns the output back, or an Unsupported error if it isn't a terminal.
+fn require_tty<W: IsTty>(io: W) -> io::Result<W> {
+    if !io.is_tty() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Stdout is not a terminal",
+        ));
+    }
+    Ok(io)
+}
+
+/// Queues the cells of `new` that differ from `old`, offset down by `top` rows.
+/// Produces the same output as walking `old.diff(new)`, without collecting the changes first.
+/// Every cell is queued if `full` is set, or if a resize has left the buffers different sizes.
+/// Returns true if anything was queued.
+fn queue_diff(
+    io: &mut impl Write,
+    old: &Buffer,
+    new: &Buffer,
+    top: u16,
+    full: bool,
+) -> io::Result<bool> {
+    let full = full || old.size() != new.size();
+    let mut changed = false;
+
+    for x in 0..new.size().x {
+        for y in 0..new.size().y {
+            let cell = new.get((x, y));
+            if full || old.get((x, y)) != cell {
+                queue!(io, cursor::MoveTo(x, top + y), Print(cell))?;
+                changed = true;
+            }
+        }
+    }
+
+    Ok(changed)
 }
 
 /// A macro that allows you to quickly check an event based off of a pattern
@@ -374,7 +991,12 @@ impl Window {
 /**
 Example
 ```rust, no_run
+# use ascii_forge::prelude::*;
+# fn main() -> std::io::Result<()> {
+# let window = Window::init()?;
 event!(window, Event::Key(e) => e.code == KeyCode::Char('q'));
+# Ok(())
+# }
 ```
 */
 #[macro_export]
@@ -391,19 +1013,876 @@ macro_rules! event {
 }
 
 /// Enables a panic hook to help you terminal still look pretty.
+/// The hook undoes whatever terminal features the active window enabled, without needing the window itself.
 pub fn handle_panics() {
+    set_panic_hook(PanicOptions::default())
+}
+
+/// A function run by the panic hook, given the panic being handled.
+pub type PanicCallback = dyn Fn(&PanicHookInfo) + Send + Sync;
+
+/// Controls what the panic hook installed by `set_panic_hook` does, on top of printing the panic as usual.
+pub struct PanicOptions {
+    /// Undo the terminal features enabled by the active window, so the panic message is readable.
+    pub restore: bool,
+    /// Append the panic message to this file, so it isn't lost once the terminal is cleared.
+    pub log_path: Option<PathBuf>,
+    /// Run this once the panic has been printed, for any cleanup of your own.
+    pub after: Option<Box<PanicCallback>>,
+}
+
+impl Default for PanicOptions {
+    fn default() -> Self {
+        Self {
+            restore: true,
+            log_path: None,
+            after: None,
+        }
+    }
+}
+
+impl PanicOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_restore(mut self, restore: bool) -> Self {
+        self.restore = restore;
+        self
+    }
+
+    pub fn with_log_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.log_path = Some(path.into());
+        self
+    }
+
+    pub fn with_after(mut self, after: impl Fn(&PanicHookInfo) + Send + Sync + 'static) -> Self {
+        self.after = Some(Box::new(after));
+        self
+    }
+}
+
+/// Installs a panic hook that does what the options ask before handing the panic to the previous hook.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+set_panic_hook(
+    PanicOptions::new()
+        .with_log_path("panic.log")
+        .with_after(|_| eprintln!("Sorry, something went wrong!")),
+);
+```
+*/
+pub fn set_panic_hook(options: PanicOptions) {
     let original_hook = take_hook();
     set_hook(Box::new(move |e| {
-        Window::new(io::stdout())
-            .expect("Window should have created for panic")
-            .restore()
-            .expect("Window should have exited for panic");
+        if options.restore {
+            let _ = restore_terminal();
+        }
+        if let Some(path) = &options.log_path {
+            if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+                let _ = writeln!(file, "{e}");
+            }
+        }
         original_hook(e);
+        if let Some(after) = &options.after {
+            after(e);
+        }
     }))
 }
 
+/// Returns the size of the terminal in columns and rows, without needing a window.
+/// Useful for deciding how to set up the window before creating it.
+/**
+`Example`
+```rust, no_run
+use ascii_forge::prelude::*;
+
+# fn main() -> std::io::Result<()> {
+let size = terminal_size()?;
+
+// Take up the bottom third of the terminal
+let mut window = Window::init_inline(size.y / 3)?;
+# Ok(())
+# }
+```
+*/
+pub fn terminal_size() -> io::Result<Vec2> {
+    Ok(size()?.into())
+}
+
+/// Undoes the terminal features enabled by the active window and disables raw mode.
+/// Used by the panic hook, where the window itself can't be reached.
+fn restore_terminal() -> io::Result<()> {
+    teardown(
+        &mut io::stdout(),
+        ACTIVE_FEATURES.swap(0, Ordering::Relaxed),
+    )?;
+    disable_raw_mode()
+}
+
+/// Writes the newlines that make room for `height` rows below the cursor, given as its column and row,
+/// starting on a fresh line if the cursor isn't at the start of one.
+/// Returns the row just below the reserved space, which is never past the bottom of the terminal.
+fn reserve_rows(
+    io: &mut impl Write,
+    (column, row): (u16, u16),
+    height: u16,
+    terminal_height: u16,
+) -> io::Result<u16> {
+    let mut lines = height;
+    if column != 0 {
+        lines = lines.saturating_add(1);
+    }
+    execute!(io, Print("\r\n".repeat(lines as usize)))?;
+
+    // Newlines past the bottom of the terminal scroll it instead of moving down.
+    Ok(row
+        .saturating_add(lines)
+        .min(terminal_height.saturating_sub(1)))
+}
+
+/// Writes the sequences that enable the terminal features the options ask for, and hides the cursor.
+fn setup(io: &mut impl Write, options: WindowOptions) -> io::Result<()> {
+    if options.alternate_screen {
+        queue!(io, EnterAlternateScreen)?;
+    }
+    if options.mouse {
+        queue!(io, EnableMouseCapture)?;
+    }
+    if options.focus {
+        queue!(io, EnableFocusChange)?;
+    }
+    if !options.line_wrap {
+        queue!(io, DisableLineWrap)?;
+    }
+    execute!(io, Hide)
+}
+
+/// Writes the sequences that undo the given terminal features, resets the colors, and shows the cursor.
+fn teardown(io: &mut impl Write, features: u8) -> io::Result<()> {
+    if features & KEYBOARD != 0 {
+        queue!(io, PopKeyboardEnhancementFlags)?;
+    }
+    if features & ALTERNATE_SCREEN != 0 {
+        queue!(io, LeaveAlternateScreen)?;
+    }
+    if features & MOUSE != 0 {
+        queue!(io, DisableMouseCapture)?;
+    }
+    if features & FOCUS != 0 {
+        queue!(io, DisableFocusChange)?;
+    }
+    if features & NO_LINE_WRAP != 0 {
+        queue!(io, EnableLineWrap)?;
+    }
+    if features & CURSOR_COLOR != 0 {
+        queue!(io, ResetCursorColor)?;
+    }
+    execute!(io, ResetColor, Show)
+}
+
 impl Drop for Window {
     fn drop(&mut self) {
         self.restore().expect("Restoration should have succeded");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+
+    /// Collects everything a headless window writes, in place of the terminal.
+    #[derive(Clone, Default)]
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+
+    impl Sink {
+        /// Takes the output written so far.
+        fn take(&self) -> String {
+            let bytes = std::mem::take(&mut *self.0.lock().unwrap());
+            String::from_utf8(bytes).unwrap()
+        }
+    }
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Builds a window of the given size that writes into the returned sink instead of the terminal.
+    /// Events that are all already waiting to be read.
+    #[derive(Clone, Default)]
+    struct Queued(Arc<Mutex<VecDeque<Event>>>);
+
+    impl Queued {
+        fn new(events: impl IntoIterator<Item = Event>) -> Self {
+            Self(Arc::new(Mutex::new(events.into_iter().collect())))
+        }
+
+        fn len(&self) -> usize {
+            self.0.lock().unwrap().len()
+        }
+    }
+
+    impl EventSource for Queued {
+        fn poll(&mut self, _: Duration) -> io::Result<bool> {
+            Ok(!self.0.lock().unwrap().is_empty())
+        }
+
+        fn read(&mut self) -> io::Result<Event> {
+            self.0
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::WouldBlock, "No events queued"))
+        }
+    }
+
+    fn headless(size: impl Into<Vec2>) -> (Window, Sink) {
+        let sink = Sink::default();
+        let mut window = Window::from_output(Output::new(sink.clone()), size.into(), None);
+        window.synchronized_output = false;
+        (window, sink)
+    }
+
+    /// Builds an inline window that has already rendered, anchored above the given row.
+    fn headless_inline(size: impl Into<Vec2>, start: u16) -> (Window, Sink) {
+        let (mut window, sink) = headless(size);
+        window.inline = Some(Inline {
+            active: true,
+            start,
+            ..Default::default()
+        });
+        (window, sink)
+    }
+
+    #[test]
+    fn resize_keeps_the_frame_and_resizes_both_buffers() {
+        let (mut window, sink) = headless((10, 4));
+        render!(window, vec2(0, 0) => [ "hello" ]);
+
+        window.insert_event(Event::Resize(6, 8));
+
+        assert_eq!(window.size(), vec2(6, 8));
+        assert!(window.buffers.iter().all(|b| b.size() == vec2(6, 8)));
+        assert_eq!(window.buffer().to_lines()[0], "hello");
+
+        // The next render redraws every cell of the new size.
+        window.render().unwrap();
+        let output = sink.take();
+        assert!(output.contains("\x1b[8;6H"));
+        assert!(!output.contains("\x1b[9;"));
+        assert!(!output.contains(";7H"));
+    }
+
+    #[test]
+    fn inline_restore_only_pops_keyboard_flags_it_pushed() {
+        let (mut window, sink) = headless_inline((10, 2), 5);
+        window.restore().unwrap();
+        assert!(!sink.take().contains("\x1b[<1u"));
+
+        window.keyboard_enhanced = true;
+        window.restore().unwrap();
+        assert!(sink.take().contains("\x1b[<1u"));
+    }
+
+    /// Queues the changes the way rendering did before `queue_diff`, by collecting `old.diff(new)` first.
+    fn queue_collected_diff(io: &mut impl Write, old: &Buffer, new: &Buffer, top: u16) {
+        for (loc, cell) in old.diff(new) {
+            queue!(io, cursor::MoveTo(loc.x, top + loc.y), Print(cell)).unwrap();
+        }
+    }
+
+    #[test]
+    fn queue_diff_matches_the_collected_diff() {
+        let blank = Buffer::new((6, 3));
+
+        let mut drawn = blank.clone();
+        drawn.set_str((0, 0), "abcdef\nghijkl\nmnopqr");
+
+        let mut sparse = drawn.clone();
+        sparse.set((1, 1), 'x');
+        sparse.set((5, 2), 'y');
+
+        let mut restyled = drawn.clone();
+        restyled.set((2, 0), Cell::new("c", ContentStyle::new().red()));
+
+        let cases = [
+            (Buffer::new((4, 4)), drawn.clone()),
+            (drawn.clone(), sparse),
+            (drawn.clone(), restyled),
+            (drawn.clone(), drawn),
+        ];
+        for (old, new) in &cases {
+            let (mut direct, mut collected) = (vec![], vec![]);
+            queue_diff(&mut direct, old, new, 2, false).unwrap();
+            queue_collected_diff(&mut collected, old, new, 2);
+
+            assert_eq!(direct, collected);
+        }
+    }
+
+    #[test]
+    fn cursor_is_only_sent_when_it_changes() {
+        let (mut window, sink) = headless((10, 4));
+        window.set_cursor_visible(true);
+        window.set_cursor((2, 1));
+
+        window.render_cursor().unwrap();
+        assert_eq!(sink.take(), "\x1b[?25h\x1b[2;3H");
+
+        window.set_cursor((2, 1));
+        window.render_cursor().unwrap();
+        assert_eq!(sink.take(), "");
+
+        window.set_cursor((4, 3));
+        window.render_cursor().unwrap();
+        assert_eq!(sink.take(), "\x1b[4;5H");
+
+        window.set_cursor_style(SetCursorStyle::SteadyBar);
+        window.render_cursor().unwrap();
+        assert_eq!(sink.take(), "\x1b[6 q");
+
+        window.set_cursor_visible(false);
+        window.render_cursor().unwrap();
+        assert_eq!(sink.take(), "\x1b[?25l");
+    }
+
+    #[test]
+    fn rendering_cells_resends_the_cursor() {
+        let (mut window, sink) = headless((10, 4));
+        window.set_cursor_visible(true);
+        window.render_cursor().unwrap();
+        sink.take();
+
+        render!(window, vec2(0, 0) => [ "x" ]);
+        window.render().unwrap();
+        window.render_cursor().unwrap();
+
+        assert!(sink.take().ends_with("\x1b[1;1H"));
+    }
+
+    #[test]
+    fn set_cursor_in_is_relative_to_the_rect() {
+        let (mut window, _) = headless((20, 10));
+        let rect = Rect::new((10, 5), (8, 1));
+
+        window.set_cursor_in(rect, (2, 0));
+        assert_eq!(window.cursor(), vec2(12, 5));
+
+        window.set_cursor_in(rect, (15, 9));
+        assert_eq!(window.cursor(), vec2(19, 9));
+    }
+
+    #[test]
+    fn screen_cursor_is_offset_by_the_inline_region() {
+        // A 3 row window anchored above row 12 covers rows 9 through 11.
+        let (mut window, _) = headless_inline((20, 3), 12);
+        window.set_cursor((4, 1));
+
+        assert_eq!(window.cursor(), vec2(4, 1));
+        assert_eq!(window.screen_cursor(), vec2(4, 10));
+    }
+
+    #[test]
+    fn swapping_clears_to_the_clear_cell() {
+        let (mut window, _) = headless((4, 2));
+        window.set_clear_cell(Cell::bg(Color::Blue));
+        render!(window, vec2(0, 0) => [ "ab" ]);
+
+        window.swap_buffers();
+
+        assert!(window
+            .buffer()
+            .cells()
+            .iter()
+            .all(|c| *c == Cell::bg(Color::Blue)));
+    }
+
+    #[test]
+    fn render_region_only_sends_changes_inside_the_rect() {
+        let (mut window, sink) = headless((6, 3));
+        render!(window, vec2(0, 0) => [ "a" ]);
+        render!(window, vec2(4, 2) => [ "b" ]);
+
+        window.render_region(Rect::new((3, 1), (3, 2))).unwrap();
+
+        assert_eq!(sink.take(), "\x1b[3;5Hb");
+        // The change outside of the rect was dropped, so it still shows what is on screen.
+        assert_eq!(window.buffer().text_at((0, 0)), Some(" "));
+
+        window.swap_buffers();
+        window.render_region(Rect::new((0, 0), (6, 3))).unwrap();
+        assert_eq!(sink.take(), "\x1b[3;5H ");
+    }
+
+    #[test]
+    fn setup_and_restore_skip_the_alternate_screen_when_disabled() {
+        let options = WindowOptions {
+            alternate_screen: false,
+            ..Default::default()
+        };
+
+        let mut output = vec![];
+        setup(&mut output, options).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("\x1b[?1049h"));
+        assert!(output.contains("\x1b[?1000h"));
+
+        let (mut window, sink) = headless((4, 2));
+        window.options = options;
+        window.restore().unwrap();
+        let output = sink.take();
+        assert!(!output.contains("\x1b[?1049l"));
+        assert!(output.contains("\x1b[?1000l"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn output_that_isnt_a_terminal_is_an_error() {
+        let file = std::fs::File::open("/dev/null").unwrap();
+
+        let err = require_tty(file).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn teardown_undoes_each_enabled_feature() {
+        let mut output = vec![];
+        teardown(&mut output, ALTERNATE_SCREEN | MOUSE | KEYBOARD).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        for sequence in ["\x1b[?1049l", "\x1b[?1000l", "\x1b[<1u", "\x1b[?25h"] {
+            assert!(output.contains(sequence), "missing {sequence:?}");
+        }
+        // Features that weren't enabled are left alone.
+        assert!(!output.contains("\x1b[?1004l"));
+    }
+
+    #[test]
+    fn draw_cursor_cell_styles_the_cell_under_the_cursor() {
+        let (mut window, _) = headless((6, 3));
+        render!(window, vec2(0, 1) => [ "abc" ]);
+        window.set_cursor((1, 1));
+
+        window.draw_cursor_cell(ContentStyle::new().reverse());
+
+        let cell = window.buffer().get((1, 1));
+        assert_eq!(cell.text(), "b");
+        assert!(cell.style().attributes.has(Attribute::Reverse));
+        assert!(!window
+            .buffer()
+            .get((0, 1))
+            .style()
+            .attributes
+            .has(Attribute::Reverse));
+    }
+
+    #[test]
+    fn shrinking_pulls_the_cursor_back_inside() {
+        let (mut window, _) = headless((10, 6));
+        window.set_cursor((8, 5));
+
+        window.insert_event(Event::Resize(4, 3));
+        window.draw_cursor_cell(ContentStyle::new().reverse());
+
+        assert_eq!(window.cursor(), vec2(3, 2));
+        assert!(window
+            .buffer()
+            .get((3, 2))
+            .style()
+            .attributes
+            .has(Attribute::Reverse));
+    }
+
+    #[test]
+    fn reserving_rows_goes_through_the_writer() {
+        let (mut window, sink) = headless((10, 3));
+
+        let start = reserve_rows(&mut window.io, (4, 2), 3, 20).unwrap();
+
+        // Off the prompt's line first, then the three rows of the window.
+        assert_eq!(sink.take(), "\r\n".repeat(4));
+        assert_eq!(start, 6);
+    }
+
+    #[test]
+    fn inline_window_taller_than_the_terminal_renders() {
+        // Reserving 8 rows in a 5 row terminal clamps the start to its last row.
+        let start = reserve_rows(&mut vec![], (0, 0), 8, 5).unwrap();
+        assert_eq!(start, 4);
+
+        let (mut window, sink) = headless_inline((6, 8), start);
+        render!(window, vec2(0, 7) => [ "x" ]);
+        window.render().unwrap();
+        window.render_region(Rect::new((0, 0), (6, 8))).unwrap();
+
+        assert!(sink.take().contains("\x1b[8;1Hx"));
+    }
+
+    #[test]
+    fn inline_start_is_only_known_once_rendered() {
+        let (window, _) = headless((10, 3));
+        assert_eq!(window.inline_start(), None);
+
+        let (mut window, _) = headless_inline((10, 3), 7);
+        assert_eq!(window.inline_start(), Some(7));
+
+        window.inline.as_mut().unwrap().active = false;
+        assert_eq!(window.inline_start(), None);
+    }
+
+    #[test]
+    fn draw_writes_to_the_active_buffer() {
+        let (mut window, _) = headless((6, 2));
+        window.insert_event(Event::FocusGained);
+
+        let seen = window.draw(|frame| {
+            render!(frame, vec2(1, 1) => [ "hi" ]);
+            (frame.size(), frame.events().len())
+        });
+
+        assert_eq!(seen, (vec2(6, 2), 1));
+        assert_eq!(window.buffer().get((1, 1)).text(), "h");
+        assert_eq!(window.buffer().get((2, 1)).text(), "i");
+
+        // The swap clears what was drawn out of the new active buffer.
+        window.swap_buffers();
+        window.draw(|frame| render!(frame, vec2(0, 0) => [ "x" ]));
+        assert_eq!(window.buffer().get((0, 0)).text(), "x");
+        assert!(window.buffer().get((1, 1)).is_empty());
+    }
+
+    #[test]
+    fn background_fills_the_buffer_after_a_swap() {
+        let (mut window, sink) = headless((3, 2));
+        window.set_background(Color::Blue);
+        render!(window, vec2(0, 0) => [ "a" ]);
+
+        window.render().unwrap();
+        // Every cell is redrawn with the new background, not just the changed ones.
+        assert_eq!(sink.take().matches("\x1b[48;5;12m").count(), 5);
+
+        window.swap_buffers();
+        assert!(window
+            .buffer()
+            .iter()
+            .all(|(_, cell)| *cell == Cell::bg(Color::Blue)));
+    }
+
+    #[test]
+    fn synchronized_output_brackets_the_frame() {
+        let (mut window, sink) = headless((4, 1));
+        render!(window, vec2(0, 0) => [ "ab" ]);
+
+        window.set_synchronized_output(true);
+        window.present().unwrap();
+        let out = sink.take();
+        assert!(out.starts_with("\x1b[?2026h"));
+        assert!(out.ends_with("\x1b[?2026l"));
+        assert!(out.contains("a"));
+
+        window.set_synchronized_output(false);
+        render!(window, vec2(0, 0) => [ "cd" ]);
+        window.present().unwrap();
+        let out = sink.take();
+        assert!(!out.contains("\x1b[?2026"));
+        assert!(out.contains("c"));
+    }
+
+    #[test]
+    fn event_count_and_input() {
+        let (mut window, _) = headless((4, 4));
+        assert_eq!(window.event_count(), 0);
+        assert!(!window.had_input());
+
+        window.insert_event(Event::FocusGained);
+        window.insert_event(Event::Resize(5, 5));
+        assert_eq!(window.event_count(), 2);
+        assert!(!window.had_input());
+
+        window.insert_event(Event::Key(KeyCode::Char('a').into()));
+        assert_eq!(window.event_count(), 3);
+        assert!(window.had_input());
+    }
+
+    #[test]
+    fn update_idle_renders_then_takes_the_waiting_events() {
+        let (mut window, sink) = headless((4, 2));
+        let key = Event::Key(KeyCode::Char('a').into());
+        let input = Queued::new([key.clone(), Event::Resize(6, 3)]);
+        window.input = Box::new(input.clone());
+
+        render!(window, vec2(0, 0) => [ "hi" ]);
+        window.update_idle().unwrap();
+
+        let out = sink.take();
+        assert!(out.contains("\x1b[1;1Hh") && out.contains("\x1b[1;2Hi"));
+        assert_eq!(window.events(), &vec![key, Event::Resize(6, 3)]);
+        assert_eq!(window.size(), vec2(6, 3));
+        assert_eq!(input.len(), 0);
+    }
+
+    #[test]
+    fn mark_dirty_redraws_every_cell() {
+        let (mut window, sink) = headless((3, 2));
+        window.render().unwrap();
+        assert_eq!(sink.take(), "");
+
+        window.mark_dirty();
+        window.render().unwrap();
+        let out = sink.take();
+        for y in 1..=2 {
+            for x in 1..=3 {
+                assert!(out.contains(&format!("\x1b[{y};{x}H")));
+            }
+        }
+
+        // Only the next render is a full one.
+        window.render().unwrap();
+        assert_eq!(sink.take(), "");
+    }
+
+    #[test]
+    fn too_small_window_shows_the_needed_size() {
+        let (mut window, _) = headless((34, 3));
+        assert!(window.require_min_size((34, 3)));
+        assert!(!window.require_min_size((35, 3)));
+        assert!(!window.require_min_size((10, 4)));
+
+        render!(window, vec2(0, 0) => [ "stale" ]);
+        window.render_too_small_message((40, 10));
+
+        let rows: Vec<String> = (0..3)
+            .map(|y| {
+                (0..34)
+                    .map(|x| window.buffer().get((x, y)).text())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rows[0].trim(), "");
+        assert_eq!(rows[1].trim(), "Terminal too small (need 40x10)");
+        assert_eq!(rows[2].trim(), "");
+
+        // Nothing is drawn once the window is large enough.
+        let (mut window, _) = headless((34, 3));
+        window.render_too_small_message((34, 3));
+        assert!(window.buffer().iter().all(|(_, cell)| cell.is_empty()));
+    }
+
+    #[test]
+    fn draining_stops_at_the_cap() {
+        let (mut window, _) = headless((20, 20));
+        let moved = |column| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Moved,
+                column,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let input = Queued::new((0..10).map(moved));
+        window.input = Box::new(input.clone());
+        window.set_max_events_per_frame(3);
+
+        window.handle_event(Duration::ZERO).unwrap();
+        assert_eq!(input.len(), 7);
+        assert_eq!(window.events(), &vec![moved(2)]);
+        assert_eq!(window.mouse_pos(), vec2(2, 0));
+
+        // The rest are left for the following frames.
+        window.handle_event(Duration::ZERO).unwrap();
+        assert_eq!(input.len(), 4);
+        assert_eq!(window.mouse_pos(), vec2(5, 0));
+
+        // A cap of zero would never read anything, so it's raised to one.
+        window.set_max_events_per_frame(0);
+        window.handle_event(Duration::ZERO).unwrap();
+        assert_eq!(input.len(), 3);
+    }
+
+    #[test]
+    fn mouse_moves_are_coalesced() {
+        let (mut window, _) = headless((20, 20));
+        let mouse = |kind, column| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column,
+                row: 1,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let click = mouse(MouseEventKind::Down(MouseButton::Left), 4);
+
+        window.insert_event(mouse(MouseEventKind::Moved, 1));
+        window.insert_event(mouse(MouseEventKind::Moved, 2));
+        window.insert_event(click.clone());
+        window.insert_event(mouse(MouseEventKind::Moved, 3));
+
+        assert_eq!(
+            window.events(),
+            &vec![click, mouse(MouseEventKind::Moved, 3)]
+        );
+        assert_eq!(window.mouse_pos(), vec2(3, 1));
+    }
+
+    #[test]
+    fn cooked_inline_stays_out_of_raw_mode() {
+        let (mut window, sink) = headless((10, 2));
+        window.inline = Some(Inline {
+            cooked: true,
+            kitty: true,
+            ..Default::default()
+        });
+        let input = Queued::new([Event::Key(KeyCode::Char('a').into())]);
+        window.input = Box::new(input.clone());
+
+        window.enter_inline(5).unwrap();
+        assert!(!crossterm::terminal::is_raw_mode_enabled().unwrap());
+        assert_eq!(window.inline_start(), Some(5));
+        let out = sink.take();
+        assert!(out.contains("\x1b[?7l"));
+        assert!(!out.contains("\x1b[?1000h"));
+        assert!(!out.contains("\x1b[?1004h"));
+        assert!(!out.contains("\x1b[>"));
+
+        // Input is left for the terminal's own line handling.
+        window.handle_event(Duration::ZERO).unwrap();
+        assert_eq!(input.len(), 1);
+        assert!(window.events().is_empty());
+        assert_eq!(
+            window.update_idle().unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn key_bindings_respect_modifiers() {
+        let (mut window, _) = headless((4, 4));
+        window.insert_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('q'),
+            KeyModifiers::CONTROL,
+        )));
+        assert!(!window.key_pressed_no_mods(KeyCode::Char('q')));
+        assert!(window.key_pressed(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+        let (mut window, _) = headless((4, 4));
+        window.insert_event(Event::Key(KeyCode::Char('q').into()));
+        assert!(window.key_pressed_no_mods(KeyCode::Char('q')));
+        assert!(!window.key_pressed(KeyCode::Char('q'), KeyModifiers::CONTROL));
+    }
+
+    #[test]
+    fn axis_cancels_out() {
+        let press = |codes: &[KeyCode]| {
+            let (mut window, _) = headless((4, 4));
+            for &code in codes {
+                window.insert_event(Event::Key(code.into()));
+            }
+            window.axis(KeyCode::Left, KeyCode::Right)
+        };
+
+        assert_eq!(press(&[]), 0);
+        assert_eq!(press(&[KeyCode::Left]), -1);
+        assert_eq!(press(&[KeyCode::Right]), 1);
+        assert_eq!(press(&[KeyCode::Left, KeyCode::Right]), 0);
+        assert_eq!(press(&[KeyCode::Up]), 0);
+    }
+
+    #[test]
+    fn panic_hook_runs_the_after_callback_and_logs() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let log =
+            std::env::temp_dir().join(format!("ascii-forge-panic-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&log);
+        let ran = Arc::new(AtomicBool::new(false));
+
+        let flag = ran.clone();
+        set_panic_hook(
+            PanicOptions::new()
+                .with_restore(false)
+                .with_log_path(&log)
+                .with_after(move |info| {
+                    if info.to_string().contains("hook test panic") {
+                        flag.store(true, Ordering::SeqCst);
+                    }
+                }),
+        );
+        let result = std::panic::catch_unwind(|| panic!("hook test panic"));
+        drop(take_hook());
+
+        assert!(result.is_err());
+        assert!(ran.load(Ordering::SeqCst));
+        let logged = std::fs::read_to_string(&log).unwrap();
+        assert!(logged.contains("hook test panic"));
+        let _ = std::fs::remove_file(&log);
+    }
+
+    #[cfg(feature = "clipboard")]
+    #[test]
+    fn set_clipboard_is_recorded() {
+        let cast =
+            std::env::temp_dir().join(format!("ascii-forge-clipboard-{}.cast", std::process::id()));
+        let (mut window, sink) = headless((4, 4));
+        window.record_to(&cast).unwrap();
+
+        window.set_clipboard("hi").unwrap();
+
+        assert_eq!(sink.take(), "\x1b]52;c;aGk=\x07");
+        let recorded = std::fs::read_to_string(&cast).unwrap();
+        let _ = std::fs::remove_file(&cast);
+        assert!(recorded.contains(r#"\u001b]52;c;aGk=\u0007"#));
+    }
+
+    #[test]
+    fn cursor_color_sequences() {
+        let ansi = |command: &dyn Fn(&mut String) -> fmt::Result| {
+            let mut out = String::new();
+            command(&mut out).unwrap();
+            out
+        };
+
+        assert_eq!(
+            ansi(&|f| SetCursorColor(Color::Rgb {
+                r: 255,
+                g: 128,
+                b: 0
+            })
+            .write_ansi(f)),
+            "\x1b]12;rgb:ff/80/00\x07"
+        );
+        assert_eq!(
+            ansi(&|f| SetCursorColor(Color::Reset).write_ansi(f)),
+            "\x1b]112\x07"
+        );
+        assert_eq!(ansi(&|f| ResetCursorColor.write_ansi(f)), "\x1b]112\x07");
+    }
+
+    #[test]
+    fn restore_resets_the_cursor_color() {
+        let (mut window, sink) = headless((4, 4));
+        window.restore().unwrap();
+        assert!(!sink.take().contains("\x1b]112\x07"));
+
+        window
+            .set_cursor_color(Color::Rgb { r: 0, g: 0, b: 255 })
+            .unwrap();
+        assert_eq!(sink.take(), "\x1b]12;rgb:00/00/ff\x07");
+
+        window.restore().unwrap();
+        assert!(sink.take().contains("\x1b]112\x07"));
+    }
+}